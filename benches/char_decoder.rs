@@ -0,0 +1,49 @@
+//! Compares `CharDecoder` on pure ascii input against mixed input.
+//!
+//! Run with `cargo bench --bench char_decoder`.
+
+use criterion::{
+  black_box, criterion_group, criterion_main, Criterion, Throughput,
+};
+use zstring::CharDecoder;
+
+const LEN: usize = 64 * 1024;
+
+fn ascii_input() -> Vec<u8> {
+  (0..LEN).map(|i| b'a' + (i % 26) as u8).collect()
+}
+
+fn mixed_input() -> Vec<u8> {
+  let mut v = "héllo wörld 日本語 😀 plain ascii text here "
+    .repeat(LEN / 32)
+    .into_bytes();
+  v.truncate(LEN);
+  v
+}
+
+fn decode(c: &mut Criterion) {
+  for (name, input) in [("ascii", ascii_input()), ("mixed", mixed_input())] {
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("next", |b| {
+      b.iter(|| {
+        // a `for` loop calls `next` once per char, unlike `fold`.
+        let mut total = 0_u32;
+        for ch in CharDecoder::from_bytes(black_box(&input)) {
+          total = total.wrapping_add(u32::from(ch));
+        }
+        total
+      })
+    });
+    group.bench_function("count", |b| {
+      b.iter(|| CharDecoder::from_bytes(black_box(&input)).count())
+    });
+    group.bench_function("collect_string", |b| {
+      b.iter(|| CharDecoder::from_bytes(black_box(&input)).collect::<String>())
+    });
+    group.finish();
+  }
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
@@ -1,6 +1,6 @@
 use core::{marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
 
-use alloc::{boxed::Box, string::String, vec::Vec};
+use alloc::{boxed::Box, ffi::CString, string::String, vec::Vec};
 
 use crate::{ZStr, ZStringError};
 
@@ -74,6 +74,167 @@ impl ZString {
     Self { nn }
   }
 
+  /// Converts a [`Box<str>`] into a [`ZString`], checking the data first.
+  ///
+  /// * If the data ends with exactly one null the allocation is reused as-is.
+  /// * If the data ends with more than one null the extra nulls are trimmed,
+  ///   which takes a new allocation.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// let b: Box<str> = Box::from("abc\0");
+  /// let zstring = ZString::try_from_boxed_str(b).unwrap();
+  /// assert_eq!(zstring, "abc");
+  ///
+  /// let extra_nulls: Box<str> = Box::from("abc\0\0\0");
+  /// let zstring = ZString::try_from_boxed_str(extra_nulls).unwrap();
+  /// assert_eq!(zstring, "abc");
+  /// ```
+  ///
+  /// ## Failure
+  /// * There must be at least one trailing null in the input (err:
+  ///   [`ZStringError::NoTrailingNulls`]).
+  /// * There must be no interior nulls in the input (err:
+  ///   [`ZStringError::InteriorNulls`]).
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// let no_null: Box<str> = Box::from("abc");
+  /// assert_eq!(
+  ///   ZString::try_from_boxed_str(no_null).unwrap_err(),
+  ///   ZStringError::NoTrailingNulls
+  /// );
+  ///
+  /// let interior: Box<str> = Box::from("a\0bc\0");
+  /// assert_eq!(
+  ///   ZString::try_from_boxed_str(interior).unwrap_err(),
+  ///   ZStringError::InteriorNulls
+  /// );
+  /// ```
+  #[inline]
+  pub fn try_from_boxed_str(b: Box<str>) -> Result<Self, ZStringError> {
+    let trimmed = b.trim_end_matches('\0');
+    if trimmed.len() == b.len() {
+      Err(ZStringError::NoTrailingNulls)
+    } else if trimmed.contains('\0') {
+      Err(ZStringError::InteriorNulls)
+    } else if trimmed.len() + 1 < b.len() {
+      Ok(trimmed.chars().collect())
+    } else {
+      // Safety: We've checked that there's exactly one null, at the end.
+      Ok(unsafe { Self::new_unchecked(b) })
+    }
+  }
+
+  /// Converts this `ZString` back into the box it was made from, including the
+  /// null terminator.
+  #[inline]
+  #[must_use]
+  pub(crate) fn into_boxed_bytes(self) -> Box<[u8]> {
+    let len = 1 + self.bytes().count();
+    let this = ManuallyDrop::new(self);
+    let slice_ptr: *mut [u8] =
+      core::ptr::slice_from_raw_parts_mut(this.nn.as_ptr(), len);
+    // Safety: This is the same box that `ZString::drop` would have freed, and
+    // `this` won't drop because it's in a `ManuallyDrop`.
+    unsafe { Box::from_raw(slice_ptr) }
+  }
+
+  /// Collects `char` values into a `ZString`, or gives an error.
+  ///
+  /// This is the fallible version of the `FromIterator` impl, which panics
+  /// instead. Prefer this when the `char` values come from an untrusted
+  /// source.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let zstring = ZString::try_from_chars("abc".chars()).unwrap();
+  /// assert_eq!(zstring, "abc");
+  ///
+  /// let err = ZString::try_from_chars(['a', '\0', 'b']).unwrap_err();
+  /// assert_eq!(err, ZStringError::InteriorNulls);
+  /// ```
+  ///
+  /// ## Failure
+  /// * If any of the `char` values are `'\0'` (err:
+  ///   [`ZStringError::InteriorNulls`]).
+  #[inline]
+  pub fn try_from_chars<I: IntoIterator<Item = char>>(
+    iter: I,
+  ) -> Result<Self, ZStringError> {
+    let mut builder = ZStringBuilder::new();
+    for ch in iter {
+      builder.push(ch)?;
+    }
+    Ok(builder.into_zstring())
+  }
+
+  /// Makes a `ZString` from format arguments.
+  ///
+  /// The text is formatted straight into the buffer that becomes the
+  /// `ZString`, instead of making a `String` and then copying it. Usually
+  /// you'd call this through the [`zformat!`](crate::zformat) macro.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let zstring = ZString::try_from_fmt(format_args!("{}-{}", 1, 2)).unwrap();
+  /// assert_eq!(zstring, "1-2");
+  /// ```
+  ///
+  /// ## Failure
+  /// * If the formatted text contains a `'\0'` (err:
+  ///   [`ZStringError::InteriorNulls`]).
+  ///
+  /// ## Panics
+  /// * If a formatting trait impl returns an error, same as with `format!`.
+  #[inline]
+  pub fn try_from_fmt(
+    args: core::fmt::Arguments<'_>,
+  ) -> Result<Self, ZStringError> {
+    let mut string = alloc::fmt::format(args);
+    if string.contains('\0') {
+      Err(ZStringError::InteriorNulls)
+    } else {
+      string.push('\0');
+      // Safety: We checked for nulls, and add exactly one null at the end.
+      Ok(unsafe { Self::new_unchecked(string.into_boxed_str()) })
+    }
+  }
+
+  /// Makes a `ZString` from bytes that might not be utf-8.
+  ///
+  /// This gives the same text as [`String::from_utf8_lossy`]: each invalid
+  /// sequence becomes a
+  /// [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER). The input is
+  /// read up to the first null (or the whole slice if there's no null), so the
+  /// output never has interior nulls.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let zstring = ZString::from_utf8_lossy(b"a\xFFb\0ignored");
+  /// assert_eq!(zstring, "a\u{FFFD}b");
+  ///
+  /// let zstring = ZString::from_utf8_lossy(b"no null");
+  /// assert_eq!(zstring, "no null");
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    let mut string = String::with_capacity(len + 1);
+    for chunk in bytes[..len].utf8_chunks() {
+      string.push_str(chunk.valid());
+      if !chunk.invalid().is_empty() {
+        string.push(char::REPLACEMENT_CHARACTER);
+      }
+    }
+    string.push('\0');
+    // Safety: None of the input bytes are 0, and in utf-8 a null can only be
+    // encoded as a 0 byte, so the valid chunks have no nulls. We add exactly
+    // one null at the end.
+    unsafe { Self::new_unchecked(string.into_boxed_str()) }
+  }
+
   /// Borrows this `ZString` as a `ZStr`.
   #[inline]
   #[must_use]
@@ -81,6 +242,127 @@ impl ZString {
     ZStr { nn: self.nn, life: PhantomData }
   }
 
+  /// Converts the string to its ASCII upper case equivalent in place.
+  ///
+  /// Non-ASCII bytes are unchanged, the same as with
+  /// [`str::make_ascii_uppercase`].
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut zstring = ZString::try_from("grüße, abc").unwrap();
+  /// zstring.make_ascii_uppercase();
+  /// assert_eq!(zstring, "GRüßE, ABC");
+  /// ```
+  #[inline]
+  pub fn make_ascii_uppercase(&mut self) {
+    self.content_bytes_mut().make_ascii_uppercase()
+  }
+
+  /// Converts the string to its ASCII lower case equivalent in place.
+  ///
+  /// Non-ASCII bytes are unchanged, the same as with
+  /// [`str::make_ascii_lowercase`].
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut zstring = ZString::try_from("GRÜSSE, ABC").unwrap();
+  /// zstring.make_ascii_lowercase();
+  /// assert_eq!(zstring, "grÜsse, abc");
+  /// ```
+  #[inline]
+  pub fn make_ascii_lowercase(&mut self) {
+    self.content_bytes_mut().make_ascii_lowercase()
+  }
+
+  /// Shortens the string to at most `new_char_len` `char` values.
+  ///
+  /// The size of the allocation isn't stored, it's found by scanning for the
+  /// null when the `ZString` drops, so the null can't just be moved earlier in
+  /// the same buffer. Instead, when the string actually gets shorter this
+  /// **reallocates** into a buffer of exactly the new length (plus the
+  /// terminator). If the string already has `new_char_len` or fewer `char`
+  /// values nothing happens.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut zstring = ZString::try_from("héllo").unwrap();
+  /// zstring.truncate(2);
+  /// assert_eq!(zstring, "hé");
+  /// zstring.truncate(10);
+  /// assert_eq!(zstring, "hé");
+  /// zstring.truncate(0);
+  /// assert_eq!(zstring, "");
+  /// ```
+  #[inline]
+  pub fn truncate(&mut self, new_char_len: usize) {
+    let content = self.as_zstr().as_str();
+    let Some((byte_len, _)) = content.char_indices().nth(new_char_len) else {
+      return;
+    };
+    let mut string = String::with_capacity(byte_len + 1);
+    string.push_str(&content[..byte_len]);
+    string.push('\0');
+    // Safety: The content of a `ZString` has no nulls, and we add exactly one
+    // null at the end.
+    *self = unsafe { Self::new_unchecked(string.into_boxed_str()) };
+  }
+
+  /// Keeps only the `char` values for which `f` returns `true`.
+  ///
+  /// Like [`String::retain`], but the predicate will never see the null
+  /// terminator, so a `'\0'` can't be kept. The size of the allocation isn't
+  /// stored, so this is an O(n) scan that builds a new buffer of exactly the
+  /// new length (plus the terminator), and then frees the old one.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut zstring = ZString::try_from("VK_KHR_surface").unwrap();
+  /// zstring.retain(|ch| ch != '_');
+  /// assert_eq!(zstring, "VKKHRsurface");
+  /// ```
+  #[inline]
+  pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+    let mut string = String::with_capacity(self.bytes().count() + 1);
+    string.extend(self.chars().filter(|&ch| f(ch)));
+    string.push('\0');
+    // Safety: The chars of a `ZString` are never null, and we add exactly one
+    // null at the end.
+    *self = unsafe { Self::new_unchecked(string.into_boxed_str()) };
+  }
+
+  /// Mutably views the bytes **before** the null terminator.
+  ///
+  /// The caller must not write a null or break the utf-8 of the data.
+  #[inline]
+  #[must_use]
+  fn content_bytes_mut(&mut self) -> &mut [u8] {
+    let len = self.bytes().count();
+    // Safety: We own these bytes, and there's `len` of them before the null.
+    unsafe { core::slice::from_raw_parts_mut(self.nn.as_ptr(), len) }
+  }
+
+  /// Leaks the `ZString`, giving a `ZStr<'static>` to the data.
+  ///
+  /// This intentionally leaks the memory, it will never be freed. It's mostly
+  /// useful for data that needs to live for the rest of the program anyway.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let zstring = ZString::try_from("abc").unwrap();
+  /// let zstr: ZStr<'static> = zstring.leak();
+  /// assert_eq!(zstr, "abc");
+  /// # // Free the memory again so that leak checkers don't flag the example.
+  /// # // Safety: both types are `repr(transparent)` over the same pointer,
+  /// # // and `zstr` isn't used after this.
+  /// # drop(unsafe { core::mem::transmute::<ZStr<'static>, ZString>(zstr) });
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn leak(self) -> ZStr<'static> {
+    let this = ManuallyDrop::new(self);
+    ZStr { nn: this.nn, life: PhantomData }
+  }
+
   /// Gets the raw pointer to this data.
   #[inline]
   #[must_use]
@@ -88,20 +370,55 @@ impl ZString {
     self.nn.as_ptr()
   }
 
+  /// Gets the raw mutable pointer to this data.
+  ///
+  /// This is for C functions that rewrite a `char*` buffer in place without
+  /// changing its length.
+  ///
+  /// ## Safety
+  /// Anything written through the pointer must keep the `ZString` valid:
+  /// * Writing a null byte **is not allowed**. The size of the allocation
+  ///   isn't stored, it's found by scanning for the first null, so an earlier
+  ///   null would make the `ZString` free the wrong amount of memory when it
+  ///   drops. To shorten the string use [`truncate`](ZString::truncate).
+  /// * Writes past the existing terminator are out of bounds.
+  /// * The bytes **must** stay valid utf-8. Methods such as `clone` and
+  ///   `Display` assume utf-8 without checking, and `retain` can turn some
+  ///   invalid sequences into an interior null. Invalid data can't be fixed
+  ///   up after the fact.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut zstring = ZString::try_from("abc").unwrap();
+  /// unsafe { *zstring.as_mut_ptr() = b'x' };
+  /// assert_eq!(zstring, "xbc");
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn as_mut_ptr(&mut self) -> *mut u8 {
+    self.nn.as_ptr()
+  }
+
   /// An iterator over the bytes of this `ZStr`.
   ///
   /// * This iterator *excludes* the terminating 0 byte.
   #[inline]
-  pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+  pub fn bytes(&self) -> crate::ZStrBytes<'_> {
     self.as_zstr().bytes()
   }
 
   /// An iterator over the decoded `char` values of this `ZStr`.
   #[inline]
-  pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+  pub fn chars(&self) -> crate::ZStrChars<'_> {
     self.as_zstr().chars()
   }
 }
+impl crate::AsZStr for ZString {
+  #[inline]
+  fn as_zstr(&self) -> ZStr<'_> {
+    ZString::as_zstr(self)
+  }
+}
 impl From<ZStr<'_>> for ZString {
   /// This is like a "to owned' style operation.
   ///
@@ -121,6 +438,11 @@ impl From<ZStr<'_>> for ZString {
   }
 }
 impl FromIterator<char> for ZString {
+  /// Collects the `char` values into a `ZString`.
+  ///
+  /// ## Panics
+  /// * If any of the `char` values are `'\0'`. Use
+  ///   [`try_from_chars`](ZString::try_from_chars) to get an error instead.
   #[inline]
   fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
     let iter = iter.into_iter();
@@ -165,6 +487,51 @@ impl TryFrom<&str> for ZString {
     }
   }
 }
+impl TryFrom<CString> for ZString {
+  type Error = core::str::Utf8Error;
+  /// Moves the allocation of a [`CString`] into a [`ZString`].
+  ///
+  /// A `CString` already has a single null terminator and no interior nulls,
+  /// so the only check is that the bytes are utf-8. The existing allocation is
+  /// re-used, no copy is made.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// # use std::ffi::CString;
+  /// let c_string = CString::new("abc").unwrap();
+  /// let zstring = ZString::try_from(c_string).unwrap();
+  /// assert_eq!(zstring, "abc");
+  ///
+  /// let c_string = CString::new(b"a\xFF".to_vec()).unwrap();
+  /// assert!(ZString::try_from(c_string).is_err());
+  /// ```
+  #[inline]
+  fn try_from(value: CString) -> Result<Self, Self::Error> {
+    let string = String::from_utf8(value.into_bytes_with_nul())
+      .map_err(|e| e.utf8_error())?;
+    // Safety: a `CString` always has exactly one null, at the end.
+    Ok(unsafe { Self::new_unchecked(string.into_boxed_str()) })
+  }
+}
+impl From<ZString> for CString {
+  /// Moves the allocation of a [`ZString`] into a [`CString`].
+  ///
+  /// No copy is made, ownership of the allocation is passed to the `CString`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// # use std::ffi::CString;
+  /// let zstring = ZString::try_from("abc").unwrap();
+  /// let c_string = CString::from(zstring);
+  /// assert_eq!(c_string.as_bytes(), b"abc");
+  /// ```
+  #[inline]
+  fn from(value: ZString) -> Self {
+    let boxed_bytes = value.into_boxed_bytes();
+    // Safety: A `ZString` has exactly one null, at the end.
+    unsafe { CString::from_vec_with_nul_unchecked(boxed_bytes.into_vec()) }
+  }
+}
 impl core::fmt::Display for ZString {
   /// Display formats the string (without outer `"`).
   ///
@@ -234,12 +601,42 @@ impl PartialEq<&str> for ZString {
 }
 impl PartialOrd<&str> for ZString {
   /// Compares based on the *byte sequence* pointed to.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let zstring = ZString::try_from("abc").unwrap();
+  /// assert!(zstring < "abd");
+  /// assert!(zstring > "ab");
+  /// ```
   #[inline]
   #[must_use]
   fn partial_cmp(&self, other: &&str) -> Option<core::cmp::Ordering> {
     Some(self.bytes().cmp(other.as_bytes().iter().copied()))
   }
 }
+impl PartialEq<str> for ZString {
+  /// A `ZString` equals a `str` if the bytes match.
+  #[inline]
+  fn eq(&self, other: &str) -> bool {
+    self.bytes().eq(other.as_bytes().iter().copied())
+  }
+}
+impl PartialOrd<str> for ZString {
+  /// Compares based on the *byte sequence* pointed to.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// use core::cmp::Ordering;
+  /// let zstring = ZString::try_from("abc").unwrap();
+  /// let abd: &str = "abd";
+  /// assert_eq!(zstring.partial_cmp(abd), Some(Ordering::Less));
+  /// assert_eq!(zstring.partial_cmp("abc"), Some(Ordering::Equal));
+  /// ```
+  #[inline]
+  fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
+    Some(self.bytes().cmp(other.as_bytes().iter().copied()))
+  }
+}
 
 impl PartialEq<ZStr<'_>> for ZString {
   /// A `ZString` equals a `ZStr` by calling `ZString::as_zstr`
@@ -289,3 +686,94 @@ pub fn zstrings_as_zstrs(zstrings: &[ZString]) -> &[ZStr<'_>] {
     core::slice::from_raw_parts(zstrings.as_ptr().cast(), zstrings.len())
   }
 }
+
+/// Builds up a [`ZString`] one piece at a time.
+///
+/// Appending to a `ZString` directly would need a new allocation every time,
+/// since a `ZString` doesn't store a capacity. Instead this keeps a growable
+/// [`String`] buffer, and then [`into_zstring`](ZStringBuilder::into_zstring)
+/// converts that buffer into a `ZString` at the end.
+///
+/// ```
+/// # use zstring::*;
+/// let mut builder = ZStringBuilder::with_capacity(16);
+/// builder.push_str("hello").unwrap();
+/// builder.push(' ').unwrap();
+/// builder.push_str("world").unwrap();
+/// assert!(builder.push_str("a\0b").is_err());
+/// let zstring = builder.into_zstring();
+/// assert_eq!(zstring, "hello world");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub struct ZStringBuilder {
+  buf: String,
+}
+impl ZStringBuilder {
+  /// Makes a new, empty builder.
+  #[inline]
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { buf: String::new() }
+  }
+
+  /// Makes a new, empty builder with at least the capacity given.
+  ///
+  /// One more byte than requested is reserved, for the null terminator.
+  #[inline]
+  #[must_use]
+  pub fn with_capacity(capacity: usize) -> Self {
+    Self { buf: String::with_capacity(capacity.saturating_add(1)) }
+  }
+
+  /// Appends a `char` to the string.
+  ///
+  /// ## Failure
+  /// * The `char` can't be `'\0'` (err: [`ZStringError::InteriorNulls`]).
+  #[inline]
+  pub fn push(&mut self, ch: char) -> Result<(), ZStringError> {
+    if ch == '\0' {
+      Err(ZStringError::InteriorNulls)
+    } else {
+      self.buf.push(ch);
+      Ok(())
+    }
+  }
+
+  /// Appends a `&str` to the string.
+  ///
+  /// ## Failure
+  /// * The `&str` can't contain any `'\0'` (err:
+  ///   [`ZStringError::InteriorNulls`]). When this happens none of the `&str`
+  ///   is appended.
+  #[inline]
+  pub fn push_str(&mut self, s: &str) -> Result<(), ZStringError> {
+    if s.contains('\0') {
+      Err(ZStringError::InteriorNulls)
+    } else {
+      self.buf.push_str(s);
+      Ok(())
+    }
+  }
+
+  /// Views the string built so far.
+  #[inline]
+  #[must_use]
+  pub fn as_str(&self) -> &str {
+    &self.buf
+  }
+
+  /// Finishes building, giving the [`ZString`].
+  ///
+  /// The buffer's allocation is re-used, though it will be shrunk to fit if it
+  /// has excess capacity.
+  #[inline]
+  #[must_use]
+  pub fn into_zstring(self) -> ZString {
+    let mut buf = self.buf;
+    buf.push('\0');
+    // Safety: `push` and `push_str` never let a null into the buffer, and
+    // we've just added a single null to the end.
+    unsafe { ZString::new_unchecked(buf.into_boxed_str()) }
+  }
+}
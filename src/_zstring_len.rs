@@ -0,0 +1,134 @@
+use core::{marker::PhantomData, ptr::NonNull};
+
+use alloc::{boxed::Box, string::String};
+
+use crate::{ZStr, ZString};
+
+/// Owning zero-terminated textual data, which also stores the length.
+///
+/// This is a sibling type to [`ZString`]. A `ZString` is a thin pointer, so it
+/// has to scan for the null terminator to find the size of its allocation when
+/// it's cloned or dropped. A `ZStringLen` keeps the length next to the
+/// pointer, so those operations (and getting the length) don't need a scan.
+///
+/// Because this is a fat pointer it's **not** suitable for direct FFI usage.
+/// Use [`as_zstr`](ZStringLen::as_zstr) to get a thin pointer to the data, or
+/// convert to a `ZString` (which doesn't copy the data).
+///
+/// ```rust
+/// # use zstring::*;
+/// let zstring = ZString::try_from("abc").unwrap();
+/// let zstring_len = ZStringLen::from(zstring);
+/// assert_eq!(zstring_len.len(), 3);
+/// assert_eq!(zstring_len.as_zstr(), "abc");
+/// let zstring: ZString = zstring_len.into();
+/// assert_eq!(zstring, "abc");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub struct ZStringLen {
+  /// Always has exactly one null, at the end.
+  b: Box<[u8]>,
+}
+impl ZStringLen {
+  /// Borrows this `ZStringLen` as a `ZStr`.
+  #[inline]
+  #[must_use]
+  pub fn as_zstr(&self) -> ZStr<'_> {
+    // Safety: The box always has a null at the end.
+    ZStr { nn: NonNull::from(&*self.b).cast(), life: PhantomData }
+  }
+
+  /// Gets the raw pointer to this data.
+  #[inline]
+  #[must_use]
+  pub fn as_ptr(&self) -> *const u8 {
+    self.b.as_ptr()
+  }
+
+  /// The length of the string in bytes, **excluding** the null terminator.
+  #[inline]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.b.len() - 1
+  }
+
+  /// If the string is empty.
+  #[inline]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Views the bytes of the string, **excluding** the null terminator.
+  #[inline]
+  #[must_use]
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.b[..self.len()]
+  }
+
+  /// View the data as a rust `&str`.
+  ///
+  /// ## Panics
+  /// * If somehow the bytes aren't utf-8 this will panic. Safe code cannot
+  ///   cause this to happen.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn as_str(&self) -> &str {
+    core::str::from_utf8(self.as_bytes()).unwrap()
+  }
+
+  /// An iterator over the decoded `char` values of this `ZStringLen`.
+  #[inline]
+  pub fn chars(&self) -> crate::ZStrChars<'_> {
+    self.as_zstr().chars()
+  }
+}
+impl crate::AsZStr for ZStringLen {
+  #[inline]
+  fn as_zstr(&self) -> ZStr<'_> {
+    ZStringLen::as_zstr(self)
+  }
+}
+impl From<ZString> for ZStringLen {
+  /// Takes the allocation of the `ZString`, scanning it once for the length.
+  #[inline]
+  fn from(value: ZString) -> Self {
+    Self { b: value.into_boxed_bytes() }
+  }
+}
+impl From<ZStringLen> for ZString {
+  /// Gives the allocation to a `ZString`, which forgets the length.
+  #[inline]
+  fn from(value: ZStringLen) -> Self {
+    // Safety: a `ZStringLen` has exactly one null, at the end, and its data
+    // is utf-8 because it can only be made from a `ZString`.
+    unsafe {
+      ZString::new_unchecked(
+        String::from_utf8_unchecked(value.b.into_vec()).into_boxed_str(),
+      )
+    }
+  }
+}
+impl From<ZStr<'_>> for ZStringLen {
+  /// This is like a "to owned" style operation.
+  #[inline]
+  fn from(value: ZStr<'_>) -> Self {
+    Self::from(ZString::from(value))
+  }
+}
+impl core::fmt::Display for ZStringLen {
+  /// Display formats the string (without outer `"`).
+  #[inline]
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    core::fmt::Display::fmt(&self.as_zstr(), f)
+  }
+}
+impl core::fmt::Debug for ZStringLen {
+  /// Debug formats with outer `"` around the string.
+  #[inline]
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    core::fmt::Debug::fmt(&self.as_zstr(), f)
+  }
+}
@@ -1,4 +1,4 @@
-use crate::{CharDecoder, ZStr, ZStringError};
+use crate::{CharDecoder, ZStr, ZStrBytes, ZStrChars, ZStringError};
 
 /// An array holding textual data that's zero termianted.
 ///
@@ -27,6 +27,121 @@ impl<const N: usize> ArrayZString<N> {
     Self([0_u8; N])
   }
 
+  /// Wraps a byte array, checking it at compile time (when used in a const).
+  ///
+  /// This mirrors the checks of the `TryFrom<&str>` impl.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// const NAME: ArrayZString<8> =
+  ///   ArrayZString::from_array_checked(*b"hello\0\0\0");
+  /// assert_eq!(NAME.as_str(), "hello");
+  /// ```
+  ///
+  /// ## Panics
+  /// * If the array doesn't contain a null.
+  /// * If there are any non-null bytes after the first null.
+  /// * If the bytes before the first null aren't utf-8.
+  ///
+  /// ```compile_fail
+  /// # use zstring::*;
+  /// const BAD: ArrayZString<4> = ArrayZString::from_array_checked(*b"ab\0c");
+  /// ```
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn from_array_checked(arr: [u8; N]) -> Self {
+    let mut i = 0;
+    while i < N && arr[i] != 0 {
+      i += 1;
+    }
+    assert!(i < N, "No trailing nulls.");
+    let (content, _) = arr.split_at(i);
+    while i < N {
+      assert!(arr[i] == 0, "Input contains interior null.");
+      i += 1;
+    }
+    assert!(core::str::from_utf8(content).is_ok(), "Input is not utf-8.");
+    Self(arr)
+  }
+
+  /// Makes an `ArrayZString` from as much of a `&str` as will fit.
+  ///
+  /// * Only the part of the `&str` before the first null (if any) is used.
+  /// * If that's more than `N-1` bytes, it's cut short at the last `char`
+  ///   boundary that fits, so a multi-byte `char` is never split.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let arr_str = ArrayZString::<6>::from_str_truncating("hello world");
+  /// assert_eq!(arr_str.as_str(), "hello");
+  ///
+  /// // 'é' is two bytes, and it doesn't fit in the last byte.
+  /// let arr_str = ArrayZString::<6>::from_str_truncating("abcdé");
+  /// assert_eq!(arr_str.as_str(), "abcd");
+  ///
+  /// let arr_str = ArrayZString::<6>::from_str_truncating("ab\0cd");
+  /// assert_eq!(arr_str.as_str(), "ab");
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn from_str_truncating(s: &str) -> Self {
+    let s = match s.find('\0') {
+      Some(i) => &s[..i],
+      None => s,
+    };
+    let mut end = s.len().min(N.saturating_sub(1));
+    while !s.is_char_boundary(end) {
+      end -= 1;
+    }
+    let mut out = Self::const_default();
+    out.0[..end].copy_from_slice(&s.as_bytes()[..end]);
+    out
+  }
+
+  /// Makes an `ArrayZString` by joining several `&str` together.
+  ///
+  /// Each part is copied directly into the array, using
+  /// [`push_str`](ArrayZString::push_str), so there's no temporary buffer.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let arr_str: ArrayZString<8> =
+  ///   ArrayZString::try_concat(&["ab", "cd", "efg"]).unwrap();
+  /// assert_eq!(arr_str.as_str(), "abcdefg");
+  ///
+  /// // one byte too many
+  /// assert_eq!(ArrayZString::<8>::try_concat(&["abcd", "efgh"]), Err(None));
+  /// ```
+  ///
+  /// ## Failure
+  /// * None of the parts can contain a null (err:
+  ///   `Some(ZStringError::InteriorNulls)`).
+  /// * The combined byte length must be less than or equal to `N-1` (err:
+  ///   `None`).
+  ///
+  /// All the parts are checked for nulls before the length is checked, so a
+  /// null is reported even when the parts are also too long.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// assert_eq!(
+  ///   ArrayZString::<4>::try_concat(&["abcdef", "g\0"]),
+  ///   Err(Some(ZStringError::InteriorNulls))
+  /// );
+  /// ```
+  #[inline]
+  pub fn try_concat(parts: &[&str]) -> Result<Self, Option<ZStringError>> {
+    if parts.iter().any(|part| part.contains('\0')) {
+      return Err(Some(ZStringError::InteriorNulls));
+    }
+    let mut out = Self::const_default();
+    for part in parts {
+      out.push_str(part)?;
+    }
+    Ok(out)
+  }
+
   /// Gets a [`ZStr`] to this data.
   ///
   /// ## Panics
@@ -38,6 +153,112 @@ impl<const N: usize> ArrayZString<N> {
     unsafe { core::mem::transmute::<*const u8, ZStr<'_>>(self.0.as_ptr()) }
   }
 
+  /// Gets the length of the string, in bytes.
+  ///
+  /// This scans the array for the first null, and it's a `const fn`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const EMPTY: ArrayZString<8> = ArrayZString::const_default();
+  /// const _: () = assert!(EMPTY.const_len() == 0);
+  ///
+  /// let arr_str: ArrayZString<8> = ArrayZString::try_from("hello").unwrap();
+  /// assert_eq!(arr_str.const_len(), 5);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub const fn const_len(&self) -> usize {
+    let mut i = 0;
+    while i < N && self.0[i] != 0 {
+      i += 1;
+    }
+    i
+  }
+
+  /// Appends a `&str` to the end of the string.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut arr_str = ArrayZString::<8>::try_from("abc").unwrap();
+  /// arr_str.push_str("def").unwrap();
+  /// assert_eq!(arr_str.as_str(), "abcdef");
+  /// ```
+  ///
+  /// ## Failure
+  /// When this fails the string is left unchanged.
+  ///
+  /// * The `&str` can't contain any nulls (err:
+  ///   `Some(ZStringError::InteriorNulls)`).
+  /// * The combined byte length must be less than or equal to `N-1` (err:
+  ///   `None`).
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut arr_str = ArrayZString::<8>::try_from("abc").unwrap();
+  /// assert_eq!(
+  ///   arr_str.push_str("d\0"),
+  ///   Err(Some(ZStringError::InteriorNulls))
+  /// );
+  /// assert_eq!(arr_str.push_str("defghi"), Err(None));
+  /// assert_eq!(arr_str.as_str(), "abc");
+  /// ```
+  #[inline]
+  pub fn push_str(&mut self, s: &str) -> Result<(), Option<ZStringError>> {
+    let len = self.const_len();
+    if s.contains('\0') {
+      Err(Some(ZStringError::InteriorNulls))
+    } else if len + s.len() < N {
+      let new_len = len + s.len();
+      self.0[len..new_len].copy_from_slice(s.as_bytes());
+      self.0[new_len] = 0;
+      Ok(())
+    } else {
+      Err(None)
+    }
+  }
+
+  /// Inserts a `&str` into the string at a byte index.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let mut arr_str = ArrayZString::<8>::try_from("ad").unwrap();
+  /// arr_str.insert_str(1, "bc").unwrap();
+  /// assert_eq!(arr_str.as_str(), "abcd");
+  /// arr_str.insert_str(4, "ef").unwrap();
+  /// assert_eq!(arr_str.as_str(), "abcdef");
+  /// ```
+  ///
+  /// ## Failure
+  /// When this fails the string is left unchanged.
+  ///
+  /// * The `&str` can't contain any nulls (err:
+  ///   `Some(ZStringError::InteriorNulls)`).
+  /// * The combined byte length must be less than or equal to `N-1` (err:
+  ///   `None`).
+  ///
+  /// ## Panics
+  /// * If `byte_idx` is past the end of the string, or isn't on a `char`
+  ///   boundary, same as with `String::insert_str`.
+  #[inline]
+  #[track_caller]
+  pub fn insert_str(
+    &mut self, byte_idx: usize, s: &str,
+  ) -> Result<(), Option<ZStringError>> {
+    let len = self.const_len();
+    assert!(self.as_str().is_char_boundary(byte_idx));
+    if s.contains('\0') {
+      Err(Some(ZStringError::InteriorNulls))
+    } else if len + s.len() < N {
+      let new_len = len + s.len();
+      self.0.copy_within(byte_idx..len, byte_idx + s.len());
+      self.0[byte_idx..byte_idx + s.len()].copy_from_slice(s.as_bytes());
+      self.0[new_len] = 0;
+      Ok(())
+    } else {
+      Err(None)
+    }
+  }
+
   /// View the data as a rust `&str`.
   ///
   /// ## Panics
@@ -47,30 +268,100 @@ impl<const N: usize> ArrayZString<N> {
   #[must_use]
   #[track_caller]
   pub fn as_str(&self) -> &str {
-    let null_position = self.0.iter().position(|&b| b == 0).unwrap();
-    core::str::from_utf8(&self.0[..null_position]).unwrap()
+    core::str::from_utf8(self.content_bytes()).unwrap()
+  }
+
+  /// The bytes before the first null (or the whole array if there's no null).
+  #[inline]
+  #[must_use]
+  fn content_bytes(&self) -> &[u8] {
+    let null_position = self.0.iter().position(|&b| b == 0).unwrap_or(N);
+    &self.0[..null_position]
   }
 
   /// An iterator over the bytes of this `ZStr`.
   ///
   /// * This iterator *excludes* the terminating 0 byte.
+  /// * This never reads past the end of the array, even if there's no null.
   #[inline]
-  pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
-    self.0.iter().copied().take_while(|&b| b != 0)
+  pub fn bytes(&self) -> ZStrBytes<'_> {
+    // an empty array has no valid pointer to read from, so point at a static
+    // null instead (it's never read because the limit is 0).
+    let p = if N == 0 { ZStr::default().as_ptr() } else { self.0.as_ptr() };
+    // Safety: The limit of `N` keeps all the reads in the array.
+    unsafe { ZStrBytes::new(p, N) }
   }
 
   /// An iterator over the decoded `char` values of this `ZStr`.
   #[inline]
-  pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+  pub fn chars(&self) -> ZStrChars<'_> {
     CharDecoder::from(self.bytes())
   }
 
+  /// An iterator over the decoded `char` values, taking the array by value.
+  ///
+  /// The iterator holds its own copy of the array, so it isn't tied to any
+  /// lifetime.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// fn shout(name: &str) -> impl Iterator<Item = char> {
+  ///   let arr_str: ArrayZString<8> = ArrayZString::from_str_truncating(name);
+  ///   arr_str.into_chars().map(|ch| ch.to_ascii_uppercase())
+  /// }
+  /// assert!(shout("hello").eq("HELLO".chars()));
+  /// ```
+  #[inline]
+  pub fn into_chars(self) -> impl Iterator<Item = char> {
+    CharDecoder::from(self.0.into_iter().take_while(|&b| b != 0))
+  }
+
   /// Gets the raw pointer to this data.
   #[inline]
   #[must_use]
   pub const fn as_ptr(self) -> *const u8 {
     self.0.as_ptr()
   }
+
+  /// Views the whole backing array, including the zero padding.
+  ///
+  /// This is useful when placing the string into a `#[repr(C)]` struct field
+  /// that always reads all `N` bytes. The array always holds at least one
+  /// null (as long as `N` isn't zero).
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// let arr_str: ArrayZString<6> = ArrayZString::try_from("abc").unwrap();
+  /// assert_eq!(arr_str.as_array(), b"abc\0\0\0");
+  /// ```
+  #[inline]
+  #[must_use]
+  pub const fn as_array(&self) -> &[u8; N] {
+    &self.0
+  }
+
+  /// Unwraps the whole backing array, including the zero padding.
+  ///
+  /// See [`as_array`](ArrayZString<N>::as_array).
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// let arr_str: ArrayZString<4> = ArrayZString::try_from("hi").unwrap();
+  /// assert_eq!(arr_str.into_array(), *b"hi\0\0");
+  /// ```
+  #[inline]
+  #[must_use]
+  pub const fn into_array(self) -> [u8; N] {
+    self.0
+  }
+}
+impl<const N: usize> crate::AsZStr for ArrayZString<N> {
+  /// ## Panics
+  /// * If the length `N` is zero, this will panic.
+  #[inline]
+  fn as_zstr(&self) -> ZStr<'_> {
+    ArrayZString::as_zstr(self)
+  }
 }
 impl<const N: usize> Default for ArrayZString<N> {
   #[inline]
@@ -114,13 +405,16 @@ impl<const N: usize> TryFrom<&str> for ArrayZString<N> {
   ///
   /// // if the array size exceeds the string size it will fit.
   /// assert!(ArrayZString::<6>::try_from("hello").is_ok());
+  ///
+  /// // an array of size 0 has no room for the null, so nothing fits.
+  /// assert_eq!(ArrayZString::<0>::try_from("").unwrap_err(), None);
   /// ```
   #[inline]
   fn try_from(value: &str) -> Result<Self, Self::Error> {
     let trimmed = value.trim_end_matches('\0');
     if trimmed.as_bytes().iter().copied().any(|b| b == 0) {
       Err(Some(ZStringError::InteriorNulls))
-    } else if trimmed.len() <= (N - 1) {
+    } else if trimmed.len() < N {
       let mut out = Self::const_default();
       out.0[..trimmed.len()].copy_from_slice(trimmed.as_bytes());
       Ok(out)
@@ -129,6 +423,73 @@ impl<const N: usize> TryFrom<&str> for ArrayZString<N> {
     }
   }
 }
+impl<const N: usize> TryFrom<ZStr<'_>> for ArrayZString<N> {
+  type Error = Option<ZStringError>;
+  /// Attempts to copy a `ZStr` into an `ArrayZString`.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// const NAME: ZStr<'static> = ZStr::from_lit("hello\0");
+  /// let arr_str: ArrayZString<16> = ArrayZString::try_from(NAME).unwrap();
+  /// assert_eq!(arr_str.as_str(), "hello");
+  /// ```
+  ///
+  /// The content bytes are copied as-is when they're utf-8. Otherwise each
+  /// invalid sequence becomes `char::REPLACEMENT_CHARACTER`, the same as with
+  /// `String::from_utf8_lossy`, so the array always holds utf-8 and never
+  /// gets an extra null.
+  ///
+  /// ## Failure
+  /// The error type matches the `TryFrom<&str>` impl. A `ZStr` can't have
+  /// interior nulls, so the only possible error is a lack of capacity (err:
+  /// `None`).
+  ///
+  /// * The byte length must be less than or equal to `N-1`.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// const NAME: ZStr<'static> = ZStr::from_lit("hello\0");
+  /// assert_eq!(ArrayZString::<5>::try_from(NAME).unwrap_err(), None);
+  /// assert!(ArrayZString::<6>::try_from(NAME).is_ok());
+  /// ```
+  #[inline]
+  fn try_from(value: ZStr<'_>) -> Result<Self, Self::Error> {
+    if N == 0 {
+      return Err(None);
+    }
+    let mut out = Self::const_default();
+    for chunk in value.as_bytes().utf8_chunks() {
+      out.push_str(chunk.valid())?;
+      if !chunk.invalid().is_empty() {
+        out.push_str("\u{FFFD}")?;
+      }
+    }
+    Ok(out)
+  }
+}
+impl<const N: usize> core::fmt::Write for ArrayZString<N> {
+  /// Appends the `&str` using [`push_str`](ArrayZString::push_str).
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// use core::fmt::Write;
+  /// let mut arr_str = ArrayZString::<16>::default();
+  /// write!(arr_str, "{}-{}", 12, "ab").unwrap();
+  /// assert_eq!(arr_str.as_str(), "12-ab");
+  ///
+  /// // if it doesn't fit, it's an error.
+  /// assert!(write!(arr_str, "{}", "a long string").is_err());
+  /// ```
+  ///
+  /// ## Failure
+  /// * If `push_str` fails this gives a [`core::fmt::Error`]. Note that a
+  ///   `write!` can call `write_str` more than once, so the string might still
+  ///   hold some of the formatted output (it's always null terminated).
+  #[inline]
+  fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    self.push_str(s).map_err(|_| core::fmt::Error)
+  }
+}
 impl<const N: usize> core::fmt::Display for ArrayZString<N> {
   /// Display formats the string (without outer `"`).
   ///
@@ -225,6 +586,62 @@ impl<const N: usize> PartialOrd<ZStr<'_>> for ArrayZString<N> {
   }
 }
 
+impl<const N: usize> PartialEq<str> for ArrayZString<N> {
+  /// An `ArrayZString<N>` equals a `str` if the bytes match.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let abc = ArrayZString::<8>::try_from("abc").unwrap();
+  /// assert!(abc == *"abc");
+  /// ```
+  #[inline]
+  fn eq(&self, other: &str) -> bool {
+    self.bytes().eq(other.as_bytes().iter().copied())
+  }
+}
+impl<const N: usize> PartialOrd<str> for ArrayZString<N> {
+  /// An `ArrayZString<N>` compares to a `str` by bytes.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let abc = ArrayZString::<8>::try_from("abc").unwrap();
+  /// assert!(abc < *"abd");
+  /// assert!(abc > *"ab");
+  /// ```
+  #[inline]
+  fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
+    Some(self.bytes().cmp(other.as_bytes().iter().copied()))
+  }
+}
+impl<const N: usize> PartialEq<&str> for ArrayZString<N> {
+  /// An `ArrayZString<N>` equals a `&str` if the bytes match.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// let abc = ArrayZString::<8>::try_from("abc").unwrap();
+  /// assert_eq!(abc, "abc");
+  /// ```
+  #[inline]
+  fn eq(&self, other: &&str) -> bool {
+    self.eq(*other)
+  }
+}
+impl<const N: usize> PartialOrd<&str> for ArrayZString<N> {
+  /// An `ArrayZString<N>` compares to a `&str` by bytes.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// # use core::cmp::{PartialOrd, Ordering};
+  /// let abc = ArrayZString::<8>::try_from("abc").unwrap();
+  /// assert_eq!(abc.partial_cmp(&"abd"), Some(Ordering::Less));
+  /// assert_eq!(abc.partial_cmp(&"ab"), Some(Ordering::Greater));
+  /// ```
+  #[inline]
+  fn partial_cmp(&self, other: &&str) -> Option<core::cmp::Ordering> {
+    self.partial_cmp(*other)
+  }
+}
+
 #[cfg(feature = "alloc")]
 impl<const N: usize> PartialEq<crate::ZString> for ArrayZString<N> {
   /// An `ArrayZString<N>` equals a `ZString` when they contain the same bytes.
@@ -245,10 +662,31 @@ impl<const N: usize> PartialOrd<crate::ZString> for ArrayZString<N> {
 }
 
 impl<const N: usize> core::hash::Hash for ArrayZString<N> {
+  /// Hashes the content exactly like the equivalent `str` would, so the
+  /// trailing nulls of the array aren't part of the hash.
   #[inline]
   fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-    for b in self.bytes() {
-      state.write_u8(b)
-    }
+    // This is what `str` does, but without needing the bytes to be utf-8.
+    state.write(self.content_bytes());
+    state.write_u8(0xFF);
+  }
+}
+impl<const N: usize> core::borrow::Borrow<str> for ArrayZString<N> {
+  /// Borrows the content as a `&str`.
+  ///
+  /// The `Eq`, `Ord`, and `Hash` impls of `ArrayZString<N>` all match those of
+  /// `str`, so this lets you use a `&str` to look up an `ArrayZString<N>` key
+  /// in a map or set.
+  ///
+  /// ```
+  /// # use zstring::*;
+  /// # use std::collections::HashSet;
+  /// let mut set: HashSet<ArrayZString<8>> = HashSet::new();
+  /// set.insert(ArrayZString::try_from("abc").unwrap());
+  /// assert!(set.contains("abc"));
+  /// ```
+  #[inline]
+  fn borrow(&self) -> &str {
+    self.as_str()
   }
 }
@@ -4,26 +4,209 @@
 ///
 /// If the bytes are not utf-8 you'll automatically get the
 /// [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER) within the output, as
-/// necessary.
+/// necessary. Use [`with_replacement`](CharDecoder::with_replacement) to pick a
+/// different `char`.
 ///
-/// Construct this iterator using `from` on any other iterator over `u8`.
+/// Construct this iterator using `from` on any other iterator over `u8`, or
+/// with [`from_str`](CharDecoder::from_str) and
+/// [`from_bytes`](CharDecoder::from_bytes) for the common slice cases.
 ///
 /// ```rust
 /// # use zstring::CharDecoder;
 /// let decoder1 = CharDecoder::from([32, 33, 34].into_iter());
 /// let decoder2 = CharDecoder::from("foobar".as_bytes().iter().copied());
+/// let decoder3 = CharDecoder::from_bytes(&[32, 33, 34]);
 /// ```
 pub struct CharDecoder<I: Iterator<Item = u8>> {
   iter: core::iter::Peekable<I>,
+  peeked: Option<Result<(char, usize), usize>>,
+  replacement: char,
 }
 impl<I: Iterator<Item = u8>> From<I> for CharDecoder<I> {
   #[inline]
   #[must_use]
   fn from(i: I) -> Self {
-    Self { iter: i.peekable() }
+    Self {
+      iter: i.peekable(),
+      peeked: None,
+      replacement: char::REPLACEMENT_CHARACTER,
+    }
+  }
+}
+impl<'b> CharDecoder<core::iter::Copied<core::slice::Iter<'b, u8>>> {
+  /// Makes a decoder over the bytes of a `&str`.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// assert!(CharDecoder::from_str("foobar").eq("foobar".chars()));
+  /// ```
+  #[inline]
+  #[must_use]
+  #[allow(clippy::should_implement_trait)]
+  pub fn from_str(s: &'b str) -> Self {
+    Self::from_bytes(s.as_bytes())
+  }
+
+  /// Makes a decoder over a `&[u8]`.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// assert!(CharDecoder::from_bytes(b"a\xFFz").eq(['a', '\u{FFFD}', 'z']));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn from_bytes(b: &'b [u8]) -> Self {
+    Self::from(b.iter().copied())
   }
 }
 impl<I: Iterator<Item = u8>> CharDecoder<I> {
+  /// Returns the next `char` without advancing the iterator.
+  ///
+  /// Peeking has to decode the next `char` (which can be up to four bytes), so
+  /// the decoded `char` is buffered within the decoder until the following call
+  /// to `next`.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// let mut decoder = CharDecoder::from("é!".as_bytes().iter().copied());
+  /// assert_eq!(decoder.peek(), Some('é'));
+  /// assert_eq!(decoder.peek(), Some('é'));
+  /// assert_eq!(decoder.next(), Some('é'));
+  /// assert_eq!(decoder.peek(), Some('!'));
+  /// assert_eq!(decoder.next(), Some('!'));
+  /// assert_eq!(decoder.peek(), None);
+  /// ```
+  #[inline]
+  pub fn peek(&mut self) -> Option<char> {
+    if self.peeked.is_none() {
+      self.peeked = self.decode_next();
+    }
+    self.peeked.map(|r| r.map_or(self.replacement, |(ch, _)| ch))
+  }
+
+  /// Returns the next `char` along with how many bytes were used to decode it.
+  ///
+  /// A valid sequence uses 1 to 4 bytes. When the bytes are invalid, the
+  /// [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER) uses only the
+  /// bytes that were consumed before the problem was found. The byte that
+  /// showed the problem is left for the next `char`, so the lengths always add
+  /// up to the total number of input bytes.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// let mut decoder = CharDecoder::from_bytes(b"a\xC3\xA9\xE2\x82z");
+  /// assert_eq!(decoder.next_with_len(), Some(('a', 1)));
+  /// assert_eq!(decoder.next_with_len(), Some(('é', 2)));
+  /// assert_eq!(decoder.next_with_len(), Some(('\u{FFFD}', 2)));
+  /// assert_eq!(decoder.next_with_len(), Some(('z', 1)));
+  /// assert_eq!(decoder.next_with_len(), None);
+  /// ```
+  #[inline]
+  pub fn next_with_len(&mut self) -> Option<(char, usize)> {
+    self.next_checked().map(|r| r.unwrap_or_else(|len| (self.replacement, len)))
+  }
+
+  /// Sets the `char` to output for malformed input.
+  ///
+  /// The default is [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER).
+  ///
+  /// If the output will be collected into a `ZString` the replacement must
+  /// not be `'\0'`, since that would be an interior null.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// let decoder = CharDecoder::from_bytes(b"a\xFFb").with_replacement('?');
+  /// assert!(decoder.eq("a?b".chars()));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn with_replacement(self, ch: char) -> Self {
+    Self { replacement: ch, ..self }
+  }
+
+  /// Skips a leading byte order mark (`EF BB BF`), if there is one.
+  ///
+  /// This is meant to be called on a new decoder, before taking any `char`
+  /// values. It decodes the first `char`, drops it if it's `'\u{FEFF}'`, and
+  /// otherwise buffers it the same as [`peek`](CharDecoder::peek) does.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// let with_bom = CharDecoder::from_bytes(b"\xEF\xBB\xBFabc").skip_bom();
+  /// assert!(with_bom.eq("abc".chars()));
+  ///
+  /// let without_bom = CharDecoder::from_bytes(b"abc").skip_bom();
+  /// assert!(without_bom.eq("abc".chars()));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn skip_bom(mut self) -> Self {
+    self.peek();
+    if let Some(Ok(('\u{FEFF}', _))) = self.peeked {
+      self.peeked = None;
+    }
+    self
+  }
+
+  /// Decodes `char` values only up until the first malformed sequence.
+  ///
+  /// Instead of giving a
+  /// [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER) for malformed
+  /// input, the iterator just ends. A `'\u{FFFD}'` that's properly encoded
+  /// within the input is still output normally.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// let decoder = CharDecoder::from_bytes(b"ab\xE2\x82cd");
+  /// assert!(decoder.valid_prefix().eq(['a', 'b']));
+  ///
+  /// let decoder = CharDecoder::from_str("a\u{FFFD}b");
+  /// assert!(decoder.valid_prefix().eq(['a', '\u{FFFD}', 'b']));
+  /// ```
+  #[inline]
+  pub fn valid_prefix(mut self) -> impl Iterator<Item = char> {
+    core::iter::from_fn(move || self.next_checked()?.ok().map(|(ch, _)| ch))
+      .fuse()
+  }
+
+  /// Decodes all remaining `char` values, writing them into a buffer as utf-8.
+  ///
+  /// Since malformed input becomes a
+  /// [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER), the output is
+  /// always valid utf-8.
+  ///
+  /// ```rust
+  /// # use zstring::CharDecoder;
+  /// let mut buf = [0_u8; 8];
+  /// let len = CharDecoder::from_bytes(b"a\xFFb").write_utf8_into(&mut buf);
+  /// assert_eq!(len, Ok(5));
+  /// assert_eq!(core::str::from_utf8(&buf[..5]), Ok("a\u{FFFD}b"));
+  ///
+  /// // only whole chars are written when there's not enough space.
+  /// let mut buf = [0_u8; 3];
+  /// let len = CharDecoder::from_str("aé!").write_utf8_into(&mut buf);
+  /// assert_eq!(len, Err(3));
+  /// ```
+  ///
+  /// ## Failure
+  /// * If the buffer runs out of space you get `Err` with the number of bytes
+  ///   written so far. The buffer will hold only whole `char` values.
+  #[inline]
+  pub fn write_utf8_into(self, buf: &mut [u8]) -> Result<usize, usize> {
+    let mut written = 0;
+    for ch in self {
+      let len = ch.len_utf8();
+      match buf.get_mut(written..written + len) {
+        Some(dest) => {
+          ch.encode_utf8(dest);
+          written += len;
+        }
+        None => return Err(written),
+      }
+    }
+    Ok(written)
+  }
+
   /// Returns the next continuation bits (pre-masked), only if the next byte is
   /// a continuation byte.
   #[inline]
@@ -34,57 +217,134 @@ impl<I: Iterator<Item = u8>> CharDecoder<I> {
       _ => None,
     }
   }
+
+  /// Gets the next decode result, using the buffered `peek` value if any.
+  #[inline]
+  #[must_use]
+  fn next_checked(&mut self) -> Option<Result<(char, usize), usize>> {
+    match self.peeked.take() {
+      Some(r) => Some(r),
+      None => self.decode_next(),
+    }
+  }
+
+  /// Decodes the next `char` (and the number of bytes used) from the
+  /// underlying bytes, ignoring any buffered `peek` value.
+  ///
+  /// Malformed input gives `Err` with the number of bytes used.
+  #[inline]
+  #[must_use]
+  fn decode_next(&mut self) -> Option<Result<(char, usize), usize>> {
+    let x = self.iter.next()?;
+    if x < 0x80 {
+      // fast path for ascii
+      Some(Ok((x as char, 1)))
+    } else {
+      Some(self.decode_non_ascii(x))
+    }
+  }
+
+  /// Decodes a `char` that starts with the non-ascii byte `x`, which has
+  /// already been taken from the underlying bytes.
+  ///
+  /// Malformed input gives `Err` with the number of bytes used.
+  #[inline]
+  fn decode_non_ascii(&mut self, x: u8) -> Result<(char, usize), usize> {
+    let x = u32::from(x);
+    match UTF8_CHAR_WIDTH[x as usize] {
+      2 => {
+        let Some(y) = self.next_continuation_bits() else {
+          return Err(1);
+        };
+        let u = ((x & 0b11111) << 6) | y;
+        char::from_u32(u).map(|ch| (ch, 2)).ok_or(2)
+      }
+      3 => {
+        let Some(y) = self.next_continuation_bits() else {
+          return Err(1);
+        };
+        let Some(z) = self.next_continuation_bits() else {
+          return Err(2);
+        };
+        let u = ((x & 0b1111) << 12) | y << 6 | z;
+        char::from_u32(u).map(|ch| (ch, 3)).ok_or(3)
+      }
+      4 => {
+        let Some(y) = self.next_continuation_bits() else {
+          return Err(1);
+        };
+        let Some(z) = self.next_continuation_bits() else {
+          return Err(2);
+        };
+        let Some(w) = self.next_continuation_bits() else {
+          return Err(3);
+        };
+        let u = ((x & 0b111) << 18) | y << 12 | z << 6 | w;
+        char::from_u32(u).map(|ch| (ch, 4)).ok_or(4)
+      }
+      _ => Err(1),
+    }
+  }
 }
+
 impl<I: Iterator<Item = u8>> Iterator for CharDecoder<I> {
   type Item = char;
 
   #[inline]
-  #[must_use]
   fn next(&mut self) -> Option<char> {
-    let x = u32::from(self.iter.next()?);
-    if x < 128 {
-      // fast path for ascii
-      Some(x as u8 as char)
-    } else {
-      match UTF8_CHAR_WIDTH[x as usize] {
-        2 => {
-          let Some(y) = self.next_continuation_bits() else {
-            return Some(char::REPLACEMENT_CHARACTER);
-          };
-          let u = ((x & 0b11111) << 6) | y;
-          Some(char::from_u32(u).unwrap_or(char::REPLACEMENT_CHARACTER))
-        }
-        3 => {
-          let Some(y) = self.next_continuation_bits() else {
-            return Some(char::REPLACEMENT_CHARACTER);
-          };
-          let Some(z) = self.next_continuation_bits() else {
-            return Some(char::REPLACEMENT_CHARACTER);
-          };
-          let u = ((x & 0b1111) << 12) | y << 6 | z;
-          Some(char::from_u32(u).unwrap_or(char::REPLACEMENT_CHARACTER))
+    let r = match self.peeked.take() {
+      Some(r) => r,
+      None => {
+        let x = self.iter.next()?;
+        if x < 0x80 {
+          // fast path for ascii, skipping the length and error tracking.
+          return Some(x as char);
         }
-        4 => {
-          let Some(y) = self.next_continuation_bits() else {
-            return Some(char::REPLACEMENT_CHARACTER);
-          };
-          let Some(z) = self.next_continuation_bits() else {
-            return Some(char::REPLACEMENT_CHARACTER);
-          };
-          let Some(w) = self.next_continuation_bits() else {
-            return Some(char::REPLACEMENT_CHARACTER);
-          };
-          let u = ((x & 0b111) << 18) | y << 12 | z << 6 | w;
-          Some(char::from_u32(u).unwrap_or(char::REPLACEMENT_CHARACTER))
-        }
-        _ => Some(char::REPLACEMENT_CHARACTER),
+        self.decode_non_ascii(x)
       }
+    };
+    Some(r.map_or(self.replacement, |(ch, _)| ch))
+  }
+
+  /// Runs of ascii bytes are passed along in a tight loop, without going
+  /// through `next` for every `char`. This is what `collect`, `count`,
+  /// `for_each`, and similar all use.
+  #[inline]
+  fn fold<B, F>(mut self, init: B, mut f: F) -> B
+  where
+    F: FnMut(B, char) -> B,
+  {
+    let mut acc = init;
+    if let Some(r) = self.peeked.take() {
+      acc = f(acc, r.map_or(self.replacement, |(ch, _)| ch));
     }
+    while let Some(x) = self.iter.next() {
+      let ch = if x < 0x80 {
+        x as char
+      } else {
+        self.decode_non_ascii(x).map_or(self.replacement, |(ch, _)| ch)
+      };
+      acc = f(acc, ch);
+    }
+    acc
+  }
+
+  /// Every `char` uses 1 to 4 bytes, so this is based on the byte iterator's
+  /// `size_hint`. For ASCII input the upper bound is exact, which lets
+  /// `collect` into a `String` allocate enough space up front.
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let peeked = usize::from(self.peeked.is_some());
+    let (lower, upper) = self.iter.size_hint();
+    (
+      lower.div_ceil(4).saturating_add(peeked),
+      upper.and_then(|u| u.checked_add(peeked)),
+    )
   }
 }
 
 /// You can't copyright facts
-const UTF8_CHAR_WIDTH: &[u8; 256] = &[
+pub(crate) const UTF8_CHAR_WIDTH: &[u8; 256] = &[
   // 1  2  3  4  5  6  7  8  9  A  B  C  D  E  F
   1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, // 0
   1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, // 1
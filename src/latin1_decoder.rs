@@ -0,0 +1,36 @@
+#![forbid(unsafe_code)]
+
+/// Decodes byte sequences as if they were Latin-1 (ISO-8859-1).
+///
+/// Every byte maps directly to the Unicode code point of the same value, so
+/// this never emits a [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER).
+///
+/// Construct this iterator using `from` on any other iterator over `u8`.
+///
+/// ```rust
+/// # use zstring::Latin1Decoder;
+/// let decoder = Latin1Decoder::from([b'a', 0xE9, 0xFF].into_iter());
+/// assert!(decoder.eq(['a', 'é', 'ÿ']));
+/// ```
+pub struct Latin1Decoder<I: Iterator<Item = u8>> {
+  iter: I,
+}
+impl<I: Iterator<Item = u8>> From<I> for Latin1Decoder<I> {
+  #[inline]
+  fn from(i: I) -> Self {
+    Self { iter: i }
+  }
+}
+impl<I: Iterator<Item = u8>> Iterator for Latin1Decoder<I> {
+  type Item = char;
+
+  #[inline]
+  fn next(&mut self) -> Option<char> {
+    self.iter.next().map(char::from)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
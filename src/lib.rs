@@ -11,11 +11,14 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-use ptr_iter::*;
+mod macros;
 
 mod char_decoder;
 pub use char_decoder::*;
 
+mod latin1_decoder;
+pub use latin1_decoder::*;
+
 mod zstr;
 pub use zstr::*;
 
@@ -26,3 +29,8 @@ pub use array_zstring::*;
 mod _zstring;
 #[cfg(feature = "alloc")]
 pub use _zstring::*;
+
+#[cfg(feature = "alloc")]
+mod _zstring_len;
+#[cfg(feature = "alloc")]
+pub use _zstring_len::*;
@@ -0,0 +1,95 @@
+/// Makes an array of [`ZStr<'static>`](crate::ZStr) from string literals.
+///
+/// Each literal gets a null appended and is checked with
+/// [`ZStr::from_lit`](crate::ZStr::from_lit) at compile time.
+///
+/// ```rust
+/// # use zstring::*;
+/// const EXTENSIONS: [ZStr<'static>; 2] =
+///   zstr_array!["VK_KHR_surface", "VK_KHR_swapchain"];
+/// assert_eq!(EXTENSIONS[0], "VK_KHR_surface");
+/// assert_eq!(EXTENSIONS[1], "VK_KHR_swapchain");
+/// ```
+///
+/// Any literal that contains a null is a build error, even at the end.
+///
+/// ```compile_fail
+/// # use zstring::*;
+/// const BAD: [ZStr<'static>; 1] = zstr_array!["a\0b"];
+/// ```
+///
+/// ```compile_fail
+/// # use zstring::*;
+/// const BAD: [ZStr<'static>; 1] = zstr_array!["a\0"];
+/// ```
+#[macro_export]
+macro_rules! zstr_array {
+  ($($s:literal),* $(,)?) => {
+    [$({
+      const _: () = assert!(
+        $crate::ZStr::from_lit(concat!($s, "\0")).eq_str(concat!($s)),
+        "Input contains a null."
+      );
+      const Z: $crate::ZStr<'static> =
+        $crate::ZStr::from_lit(concat!($s, "\0"));
+      Z
+    }),*]
+  };
+}
+
+/// Makes a [`ZStr<'static>`](crate::ZStr) by concatenating string literals.
+///
+/// The pieces are joined with `concat!`, a null is appended, and the result is
+/// checked with [`ZStr::from_lit`](crate::ZStr::from_lit) at compile time, so
+/// nothing is allocated at runtime.
+///
+/// ```rust
+/// # use zstring::*;
+/// const SURFACE: ZStr<'static> = zstr_concat!("VK_", "KHR_", "surface");
+/// assert_eq!(SURFACE, "VK_KHR_surface");
+/// ```
+///
+/// Any piece that contains a null is a build error, even at the end.
+///
+/// ```compile_fail
+/// # use zstring::*;
+/// const BAD: ZStr<'static> = zstr_concat!("a", "b\0");
+/// ```
+#[macro_export]
+macro_rules! zstr_concat {
+  ($($s:literal),+ $(,)?) => {{
+    $(
+      const _: () = assert!(
+        $crate::ZStr::from_lit(concat!($s, "\0")).eq_str(concat!($s)),
+        "Input contains a null."
+      );
+    )+
+    const Z: $crate::ZStr<'static> =
+      $crate::ZStr::from_lit(concat!($($s,)+ "\0"));
+    Z
+  }};
+}
+
+/// Formats text into a [`ZString`](crate::ZString), like `format!`.
+///
+/// This expands to a call to
+/// [`ZString::try_from_fmt`](crate::ZString::try_from_fmt), so you get a
+/// `Result` that's an error if the formatted text contains a null.
+///
+/// ```rust
+/// # use zstring::*;
+/// let (a, b) = ("foo", 7);
+/// let zstring: ZString = zformat!("{a}-{b}").unwrap();
+/// assert_eq!(zstring, "foo-7");
+///
+/// let err = zformat!("{}", "a\0b").unwrap_err();
+/// assert_eq!(err, ZStringError::InteriorNulls);
+/// ```
+#[macro_export]
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+macro_rules! zformat {
+  ($($arg:tt)*) => {
+    $crate::ZString::try_from_fmt(::core::format_args!($($arg)*))
+  };
+}
@@ -32,6 +32,26 @@ impl<'a> ZStr<'a> {
   /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
   /// ```
   ///
+  /// Any const `&'static str` expression works, not just a literal, as long as
+  /// it includes the null.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const NAME: &str = "surface\0";
+  /// const SURFACE: ZStr<'static> = ZStr::from_lit(NAME);
+  /// assert_eq!(SURFACE, "surface");
+  ///
+  /// const fn mode_name(debug: bool) -> &'static str {
+  ///   if debug {
+  ///     "debug\0"
+  ///   } else {
+  ///     "release\0"
+  ///   }
+  /// }
+  /// const MODE: ZStr<'static> = ZStr::from_lit(mode_name(true));
+  /// assert_eq!(MODE, "debug");
+  /// ```
+  ///
   /// ## Panics
   /// * If `try_from` would return an error, this will panic instead. Because
   ///   this is intended for compile time constants, the panic will "just"
@@ -39,49 +59,1016 @@ impl<'a> ZStr<'a> {
   #[inline]
   #[track_caller]
   pub const fn from_lit(s: &'static str) -> ZStr<'static> {
+    match ZStr::try_from_lit(s) {
+      Ok(z) => z,
+      Err(ZStringError::NoTrailingNulls) => panic!("No trailing nulls."),
+      Err(ZStringError::InteriorNulls) => {
+        panic!("Input contains interior null.")
+      }
+    }
+  }
+
+  /// Makes a `ZStr<'static>` from a `&'static str`, or gives an error.
+  ///
+  /// This does the same checks as [`from_lit`](ZStr::from_lit), and it's also
+  /// a `const fn`, but it returns an error instead of panicking.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// use ZStringError::*;
+  /// const FOO: Result<ZStr<'static>, ZStringError> =
+  ///   ZStr::try_from_lit("foo\0");
+  /// assert_eq!(FOO.unwrap(), "foo");
+  ///
+  /// const EMPTY: Result<ZStr<'static>, ZStringError> =
+  ///   ZStr::try_from_lit("\0");
+  /// assert_eq!(EMPTY.unwrap(), "");
+  ///
+  /// assert_eq!(ZStr::try_from_lit("foo"), Err(NoTrailingNulls));
+  /// assert_eq!(ZStr::try_from_lit(""), Err(NoTrailingNulls));
+  /// assert_eq!(ZStr::try_from_lit("f\0o\0"), Err(InteriorNulls));
+  /// ```
+  ///
+  /// ## Failure
+  /// * The same as the `TryFrom<&str>` impl.
+  #[inline]
+  pub const fn try_from_lit(
+    s: &'static str,
+  ) -> Result<ZStr<'static>, ZStringError> {
     let bytes = s.as_bytes();
-    let mut tail_index = bytes.len() - 1;
-    while bytes[tail_index] == 0 {
-      tail_index -= 1;
+    let mut end = bytes.len();
+    while end > 0 && bytes[end - 1] == 0 {
+      end -= 1;
+    }
+    if end == bytes.len() {
+      return Err(ZStringError::NoTrailingNulls);
     }
-    assert!(tail_index < bytes.len() - 1, "No trailing nulls.");
     let mut i = 0;
-    while i < tail_index {
+    while i < end {
       if bytes[i] == 0 {
-        panic!("Input contains interior null.");
+        return Err(ZStringError::InteriorNulls);
       }
       i += 1;
     }
-    ZStr {
+    Ok(ZStr {
       // Safety: References can't ever be null.
       nn: unsafe { NonNull::new_unchecked(s.as_ptr() as *mut u8) },
       life: PhantomData,
-    }
+    })
+  }
+
+  /// Splits a byte slice at the first null, giving a `ZStr` and the bytes after
+  /// the null.
+  ///
+  /// This is intended for parsing a buffer that holds several null-terminated
+  /// records packed one after the other.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// let (z, rest) = ZStr::content_and_rest(b"ab\0cd\0").unwrap();
+  /// assert_eq!(z, "ab");
+  /// assert_eq!(rest, b"cd\0");
+  ///
+  /// let (z, rest) = ZStr::content_and_rest(rest).unwrap();
+  /// assert_eq!(z, "cd");
+  /// assert!(rest.is_empty());
+  /// ```
+  ///
+  /// ## Failure
+  /// * If there's no null in the slice you get `None`.
+  /// * If the bytes before the first null aren't utf-8 you get `None`.
+  #[inline]
+  #[must_use]
+  pub fn content_and_rest(bytes: &'a [u8]) -> Option<(ZStr<'a>, &'a [u8])> {
+    let null_position = bytes.iter().position(|&b| b == 0)?;
+    core::str::from_utf8(&bytes[..null_position]).ok()?;
+    let z = ZStr {
+      // Safety: References can't ever be null.
+      nn: unsafe { NonNull::new_unchecked(bytes.as_ptr() as *mut u8) },
+      life: PhantomData,
+    };
+    Some((z, &bytes[null_position + 1..]))
   }
 
   /// An iterator over the bytes of this `ZStr`.
   ///
   /// * This iterator **excludes** the terminating 0 byte.
   #[inline]
-  pub fn bytes(self) -> impl Iterator<Item = u8> + 'a {
+  pub fn bytes(self) -> ZStrBytes<'a> {
     // Safety: per the type safety docs, whoever made this `ZStr` promised that
     // we can read the pointer's bytes until we find a 0 byte.
-    unsafe { ConstPtrIter::read_until_default(self.nn.as_ptr()) }
+    unsafe { ZStrBytes::new(self.nn.as_ptr(), usize::MAX) }
+  }
+
+  /// An iterator over the bytes of this `ZStr`, **including** the terminating
+  /// 0 byte.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// assert!(FOO.bytes_with_null().eq(*b"foo\0"));
+  /// ```
+  #[inline]
+  pub fn bytes_with_null(self) -> impl Iterator<Item = u8> + 'a {
+    self.bytes().chain([0])
+  }
+
+  /// An iterator over the bytes of this `ZStr`, with a known length.
+  ///
+  /// * This iterator **excludes** the terminating 0 byte.
+  /// * This scans for the terminator once when it's called, unlike
+  ///   [`bytes`](ZStr::bytes), which finds the end as it goes.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// assert_eq!(FOO.bytes_exact().len(), 3);
+  /// ```
+  #[inline]
+  pub fn bytes_exact(self) -> impl ExactSizeIterator<Item = u8> + 'a {
+    self.as_bytes().iter().copied()
   }
 
   /// An iterator over the decoded `char` values of this `ZStr`.
   #[inline]
-  pub fn chars(self) -> impl Iterator<Item = char> + 'a {
+  pub fn chars(self) -> ZStrChars<'a> {
     CharDecoder::from(self.bytes())
   }
 
+  /// Gets the byte length of the `char` that starts at `byte_index`.
+  ///
+  /// This only looks at the lead byte, the same as the [`CharDecoder`] does.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const TEXT: ZStr<'static> = ZStr::from_lit("aé😀\0");
+  /// assert_eq!(TEXT.char_len_at(0), Some(1));
+  /// assert_eq!(TEXT.char_len_at(1), Some(2));
+  /// assert_eq!(TEXT.char_len_at(2), None); // inside the 'é'
+  /// assert_eq!(TEXT.char_len_at(3), Some(4));
+  /// assert_eq!(TEXT.char_len_at(7), None); // the null
+  /// ```
+  ///
+  /// ## Failure
+  /// * If `byte_index` is at the null terminator or past it.
+  /// * If the byte at `byte_index` isn't the start of a `char` (such as a
+  ///   continuation byte).
+  #[inline]
+  #[must_use]
+  pub fn char_len_at(self, byte_index: usize) -> Option<usize> {
+    let b = self.bytes().nth(byte_index)?;
+    match crate::char_decoder::UTF8_CHAR_WIDTH[usize::from(b)] {
+      0 => None,
+      width => Some(usize::from(width)),
+    }
+  }
+
+  /// Counts the decoded `char` values of the string.
+  ///
+  /// This is the same as `self.chars().count()`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const TEXT: ZStr<'static> = ZStr::from_lit("naïve\0");
+  /// assert_eq!(TEXT.chars_count(), 5);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn chars_count(self) -> usize {
+    self.chars().count()
+  }
+
+  /// Counts how many terminal columns the string takes up when printed.
+  ///
+  /// * With the `unicode-width` feature enabled this uses the
+  ///   [`unicode-width`](https://docs.rs/unicode-width) crate: wide chars
+  ///   (such as most CJK) count as 2 columns, and control chars and combining
+  ///   marks count as 0.
+  /// * Without the feature this falls back to counting `char` values, which
+  ///   is only correct when every char is a single column.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const NAME: ZStr<'static> = ZStr::from_lit("abc\0");
+  /// assert_eq!(NAME.display_width(), 3);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn display_width(self) -> usize {
+    #[cfg(feature = "unicode-width")]
+    {
+      use unicode_width::UnicodeWidthChar;
+      self.chars().map(|ch| ch.width().unwrap_or(0)).sum()
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    {
+      self.chars_count()
+    }
+  }
+
+  /// Checks if two strings have the same number of `char` values.
+  ///
+  /// Both strings are decoded in lockstep, and this returns as soon as one of
+  /// them runs out, so the longer string is never fully decoded.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const A: ZStr<'static> = ZStr::from_lit("naïve\0");
+  /// const B: ZStr<'static> = ZStr::from_lit("hello\0");
+  /// const C: ZStr<'static> = ZStr::from_lit("hello world\0");
+  /// assert!(A.same_char_count(B));
+  /// assert!(!A.same_char_count(C));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn same_char_count(self, other: ZStr<'_>) -> bool {
+    let mut a = self.chars();
+    let mut b = other.chars();
+    loop {
+      match (a.next(), b.next()) {
+        (Some(_), Some(_)) => continue,
+        (None, None) => return true,
+        _ => return false,
+      }
+    }
+  }
+
+  /// Counts how many `char` values come before the first `delim`.
+  ///
+  /// If `delim` doesn't appear this is the total `char` count of the string.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const ROW: ZStr<'static> = ZStr::from_lit("naïve\tvalue\0");
+  /// assert_eq!(ROW.chars_until('\t'), 5);
+  /// assert_eq!(ROW.chars_until('!'), 11);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn chars_until(self, delim: char) -> usize {
+    self.chars().take_while(|&ch| ch != delim).count()
+  }
+
+  /// Finds the byte index of the first `b` in the string.
+  ///
+  /// * With the `memchr` feature enabled the terminator is found first, and
+  ///   then the content is searched with [`memchr`](https://docs.rs/memchr).
+  /// * Without the feature the search for `b` and the scan for the null
+  ///   terminator happen in the same pass, stopping at whichever comes first.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const PAIR: ZStr<'static> = ZStr::from_lit("key=value\0");
+  /// assert_eq!(PAIR.find_byte(b'='), Some(3));
+  /// assert_eq!(PAIR.find_byte(b':'), None);
+  /// // the terminator isn't part of the content.
+  /// assert_eq!(PAIR.find_byte(0), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn find_byte(self, b: u8) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+      memchr::memchr(b, self.as_bytes())
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+      self.bytes().position(|x| x == b)
+    }
+  }
+
+  /// An iterator over the byte index of each place `pat` appears.
+  ///
+  /// For a multi-byte `char` the index is the start of the sequence.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const PATH: ZStr<'static> = ZStr::from_lit("a/bé/cé\0");
+  /// assert!(PATH.matches('/').eq([1, 5]));
+  /// assert!(PATH.matches('é').eq([3, 7]));
+  /// assert_eq!(PATH.matches('/').count(), 2);
+  /// ```
+  #[inline]
+  pub fn matches(self, pat: char) -> impl Iterator<Item = usize> + 'a {
+    let mut buf = [0_u8; 4];
+    let pat_len = pat.encode_utf8(&mut buf).len();
+    let bytes = self.as_bytes();
+    (0..bytes.len()).filter(move |&i| bytes[i..].starts_with(&buf[..pat_len]))
+  }
+
+  /// An iterator over the bytes of this `ZStr`, in reverse order.
+  ///
+  /// * This iterator *excludes* the terminating 0 byte.
+  /// * This has to scan for the terminator before the first byte can be
+  ///   output, and then it walks backwards from there.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FILE: ZStr<'static> = ZStr::from_lit("image.png\0");
+  /// assert!(FILE.bytes_rev().take(4).eq(b"gnp.".iter().copied()));
+  /// ```
+  #[inline]
+  pub fn bytes_rev(self) -> impl Iterator<Item = u8> + 'a {
+    self.as_bytes().iter().rev().copied()
+  }
+
+  /// An iterator over the decoded `char` values of this `ZStr`, in reverse
+  /// order.
+  ///
+  /// * This has to scan for the terminator before the first `char` can be
+  ///   decoded, and then it decodes backwards from there.
+  /// * When the bytes are valid utf-8 the output is exactly the reverse of
+  ///   [`chars`](ZStr::chars). When they're not, you'll get a
+  ///   [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER) for each byte
+  ///   that isn't part of a valid sequence, which might not be the same number
+  ///   of replacements that `chars` gives.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("aé😀\0");
+  /// assert!(FOO.rev_chars().eq("aé😀".chars().rev()));
+  /// ```
+  #[inline]
+  pub fn rev_chars(self) -> impl Iterator<Item = char> + 'a {
+    let mut bytes = self.as_bytes();
+    core::iter::from_fn(move || {
+      let (&last, init) = bytes.split_last()?;
+      if last < 128 {
+        bytes = init;
+        return Some(last as char);
+      }
+      // walk back over (at most 3) continuation bytes to the lead byte.
+      let mut start = init.len();
+      while start > 0 && bytes.len() - start < 4 && bytes[start] >> 6 == 0b10 {
+        start -= 1;
+      }
+      match core::str::from_utf8(&bytes[start..]) {
+        Ok(s) => {
+          bytes = &bytes[..start];
+          s.chars().next()
+        }
+        Err(_) => {
+          bytes = init;
+          Some(char::REPLACEMENT_CHARACTER)
+        }
+      }
+    })
+  }
+
+  /// Feeds the bytes of the string into a hasher.
+  ///
+  /// The bytes are fed one at a time as the string is scanned, so there's only
+  /// a single pass over the data. This is exactly what the `Hash` impl does,
+  /// so it's for when you want to drive a specific `Hasher` by hand.
+  ///
+  /// No length or end marker is written, unlike how `str` hashes itself with an
+  /// extra `0xFF` byte. That means this won't give the same hash as a `str`
+  /// with the same content.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// use core::hash::{BuildHasher, Hasher};
+  /// use std::collections::hash_map::RandomState;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// let state = RandomState::new();
+  /// let mut hasher = state.build_hasher();
+  /// FOO.hash_bytes(&mut hasher);
+  /// assert_eq!(hasher.finish(), state.hash_one(FOO));
+  /// ```
+  #[inline]
+  pub fn hash_bytes<H: core::hash::Hasher>(self, state: &mut H) {
+    for b in self.bytes() {
+      state.write_u8(b)
+    }
+  }
+
+  /// Displays at most `max_chars` of the string, with a `…` on the end if any
+  /// were cut off.
+  ///
+  /// This counts decoded `char` values (not bytes), so a multi-byte `char` is
+  /// never split.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const TEXT: ZStr<'static> = ZStr::from_lit("héllo world\0");
+  /// assert_eq!(format!("{}", TEXT.display_truncated(5)), "héllo…");
+  /// assert_eq!(format!("{}", TEXT.display_truncated(11)), "héllo world");
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn display_truncated(
+    self, max_chars: usize,
+  ) -> impl core::fmt::Display + 'a {
+    DisplayTruncated { z: self, max_chars }
+  }
+
+  /// Displays the string with each `char` escaped by
+  /// [`char::escape_default`].
+  ///
+  /// The output is always printable ASCII, so it's safe to put in a log even
+  /// when the string is untrusted. Bytes that aren't utf-8 are decoded as
+  /// `char::REPLACEMENT_CHARACTER`, which then displays as `\u{fffd}`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const TEXT: ZStr<'static> = ZStr::from_lit("tab\there\né\0");
+  /// assert_eq!(
+  ///   format!("{}", TEXT.escape_default()),
+  ///   "tab\\there\\n\\u{e9}"
+  /// );
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn escape_default(self) -> impl core::fmt::Display + 'a {
+    EscapeDefault { z: self }
+  }
+
+  /// Makes a `ZStr` from a raw pointer, such as a `char*` from C.
+  ///
+  /// In debug builds this asserts that the pointer isn't null.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// let bytes = "abc\0";
+  /// let zstr: ZStr<'_> = unsafe { ZStr::from_ptr(bytes.as_ptr()) };
+  /// assert_eq!(zstr, "abc");
+  /// ```
+  ///
+  /// ## Safety
+  /// * The pointer must be non-null.
+  /// * The pointer must follow all the rules in the [type docs](ZStr), it
+  ///   must point at non-zero bytes followed by a zero byte, and stay valid to
+  ///   read for the whole lifetime `'a`. Nothing ties `'a` to the source of
+  ///   the pointer, so pick it carefully.
+  /// * The bytes before the null **must** be utf-8. Other safe code relies on
+  ///   this, such as converting to a `ZString`. If the bytes might not be
+  ///   utf-8 use [`from_ptr_checked`](ZStr::from_ptr_checked) instead.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const unsafe fn from_ptr(ptr: *const u8) -> ZStr<'a> {
+    debug_assert!(!ptr.is_null());
+    // Safety: the caller promises that the pointer isn't null.
+    ZStr {
+      nn: unsafe { NonNull::new_unchecked(ptr as *mut u8) },
+      life: PhantomData,
+    }
+  }
+
+  /// Makes a `ZStr` from a raw pointer, checking that the bytes are utf-8.
+  ///
+  /// This scans to the null and validates the bytes along the way. It's still
+  /// `unsafe` because there's no way to check that the pointer itself is
+  /// valid.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// let good = b"abc\0";
+  /// let zstr = unsafe { ZStr::from_ptr_checked(good.as_ptr()) }.unwrap();
+  /// assert_eq!(zstr, "abc");
+  ///
+  /// let bad = b"a\xFF\0";
+  /// assert!(unsafe { ZStr::from_ptr_checked(bad.as_ptr()) }.is_err());
+  /// ```
+  ///
+  /// ## Safety
+  /// * Same as with [`from_ptr`](ZStr::from_ptr), except that the bytes don't
+  ///   need to be utf-8, because that's checked.
+  ///
+  /// ## Failure
+  /// * If the bytes before the null aren't utf-8.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn from_ptr_checked(
+    ptr: *const u8,
+  ) -> Result<ZStr<'a>, core::str::Utf8Error> {
+    debug_assert!(!ptr.is_null());
+    // Safety: the caller promises that the pointer isn't null.
+    let zstr = ZStr {
+      nn: unsafe { NonNull::new_unchecked(ptr as *mut u8) },
+      life: PhantomData,
+    };
+    // Only give out the `ZStr` once we know the bytes are utf-8.
+    zstr.try_as_str()?;
+    Ok(zstr)
+  }
+
   /// Gets the raw pointer to this data.
   #[inline]
   #[must_use]
   pub const fn as_ptr(self) -> *const u8 {
     self.nn.as_ptr()
   }
+
+  /// Gets the range of pointers spanning the bytes of this `ZStr`.
+  ///
+  /// * The `start` is the same as [`as_ptr`](ZStr::as_ptr).
+  /// * The `end` points **at** the null terminator (one past the last non-null
+  ///   byte), so the range covers the bytes but not the terminator.
+  /// * This has to scan for the terminator to find the `end`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// let range = FOO.as_ptr_range();
+  /// assert_eq!(range.start, FOO.as_ptr());
+  /// assert_eq!(unsafe { range.end.offset_from(range.start) }, 3);
+  /// assert_eq!(unsafe { *range.end }, 0);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn as_ptr_range(self) -> core::ops::Range<*const u8> {
+    self.as_bytes().as_ptr_range()
+  }
+
+  /// Views the bytes of this `ZStr` as a slice.
+  ///
+  /// * The slice **excludes** the terminating 0 byte.
+  /// * This has to scan for the terminator to know the length.
+  #[inline]
+  #[must_use]
+  pub(crate) fn as_bytes(self) -> &'a [u8] {
+    let len = self.bytes().count();
+    // Safety: per the type safety docs, the `len` bytes before the first 0
+    // byte are all valid to read for the lifetime `'a`.
+    unsafe { core::slice::from_raw_parts(self.nn.as_ptr(), len) }
+  }
+
+  /// View the data as a rust `&str`.
+  ///
+  /// * This has to scan for the terminator to know the length.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// assert_eq!(FOO.as_str(), "foo");
+  /// ```
+  ///
+  /// ## Panics
+  /// * If somehow the bytes aren't utf-8 this will panic. Safe code cannot
+  ///   cause this to happen.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn as_str(self) -> &'a str {
+    core::str::from_utf8(self.as_bytes()).unwrap()
+  }
+
+  /// View the data as a rust `&str`, if it's utf-8.
+  ///
+  /// A `ZStr` made with safe code is always utf-8, but one made from a raw
+  /// pointer might not be. This lets you check without risking a panic.
+  ///
+  /// * This has to scan for the terminator to know the length.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// assert_eq!(FOO.try_as_str(), Ok("foo"));
+  /// ```
+  #[inline]
+  pub fn try_as_str(self) -> Result<&'a str, core::str::Utf8Error> {
+    core::str::from_utf8(self.as_bytes())
+  }
+
+  /// Gets a sub-slice of the string by byte range.
+  ///
+  /// This works like [`str::get`], and the output is a `&str` because the
+  /// sub-slice isn't necessarily null terminated.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const TEXT: ZStr<'static> = ZStr::from_lit("key=välue\0");
+  /// assert_eq!(TEXT.get(0..3), Some("key"));
+  /// assert_eq!(TEXT.get(4..10), Some("välue"));
+  /// assert_eq!(TEXT.get(4..6), None); // not a char boundary
+  /// assert_eq!(TEXT.get(4..11), None); // past the end
+  /// ```
+  ///
+  /// ## Failure
+  /// * If the range is out of bounds or not on `char` boundaries.
+  /// * If the bytes aren't utf-8.
+  #[inline]
+  #[must_use]
+  pub fn get(self, range: core::ops::Range<usize>) -> Option<&'a str> {
+    self.try_as_str().ok()?.get(range)
+  }
+
+  /// Gets the rest of the string after `prefix`, if the string starts with
+  /// `prefix`.
+  ///
+  /// The output is still null terminated, so this gives a `ZStr` (it just
+  /// moves the pointer forward). Only the bytes of `prefix` are checked, this
+  /// doesn't need to scan the whole string.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const EXT: ZStr<'static> = ZStr::from_lit("VK_KHR_surface\0");
+  /// assert_eq!(EXT.strip_prefix("VK_").unwrap(), "KHR_surface");
+  /// assert!(EXT.strip_prefix("VK_EXT_").is_none());
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn strip_prefix(self, prefix: &str) -> Option<ZStr<'a>> {
+    let mut bytes = self.bytes();
+    if prefix.bytes().all(|b| bytes.next() == Some(b)) {
+      Some(ZStr {
+        // Safety: We just read `prefix.len()` non-null bytes, so this pointer
+        // is at most pointing to the null terminator.
+        nn: unsafe {
+          NonNull::new_unchecked(self.nn.as_ptr().add(prefix.len()))
+        },
+        life: PhantomData,
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Gets the string before `suffix`, if the string ends with `suffix`.
+  ///
+  /// The output isn't null terminated, so it's a `&str` rather than a `ZStr`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FILE: ZStr<'static> = ZStr::from_lit("image.png\0");
+  /// assert_eq!(FILE.strip_suffix(".png"), Some("image"));
+  /// assert_eq!(FILE.strip_suffix(".jpg"), None);
+  /// ```
+  ///
+  /// ## Failure
+  /// * If the string doesn't end with `suffix`.
+  /// * If the bytes aren't utf-8.
+  #[inline]
+  #[must_use]
+  pub fn strip_suffix(self, suffix: &str) -> Option<&'a str> {
+    self.try_as_str().ok()?.strip_suffix(suffix)
+  }
+
+  /// Splits the string at the first `delim`, giving the parts before and
+  /// after it.
+  ///
+  /// The output isn't null terminated, so it's `&str` rather than `ZStr`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const OPT: ZStr<'static> = ZStr::from_lit("key=value=more\0");
+  /// assert_eq!(OPT.split_once('='), Some(("key", "value=more")));
+  /// assert_eq!(OPT.split_once(':'), None);
+  /// ```
+  ///
+  /// ## Failure
+  /// * If `delim` isn't in the string.
+  /// * If the bytes aren't utf-8.
+  #[inline]
+  #[must_use]
+  pub fn split_once(self, delim: char) -> Option<(&'a str, &'a str)> {
+    self.try_as_str().ok()?.split_once(delim)
+  }
+
+  /// Splits the string at the last `delim`, giving the parts before and after
+  /// it.
+  ///
+  /// The output isn't null terminated, so it's `&str` rather than `ZStr`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const OPT: ZStr<'static> = ZStr::from_lit("key=value=more\0");
+  /// assert_eq!(OPT.rsplit_once('='), Some(("key=value", "more")));
+  /// assert_eq!(OPT.rsplit_once(':'), None);
+  /// ```
+  ///
+  /// ## Failure
+  /// * If `delim` isn't in the string.
+  /// * If the bytes aren't utf-8.
+  #[inline]
+  #[must_use]
+  pub fn rsplit_once(self, delim: char) -> Option<(&'a str, &'a str)> {
+    self.try_as_str().ok()?.rsplit_once(delim)
+  }
+
+  /// The string with leading and trailing whitespace removed.
+  ///
+  /// The output isn't null terminated, so it's a `&str` rather than a `ZStr`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FIELD: ZStr<'static> = ZStr::from_lit("  name  \0");
+  /// assert_eq!(FIELD.trim(), "name");
+  /// assert_eq!(FIELD.trim_start(), "name  ");
+  /// assert_eq!(FIELD.trim_end(), "  name");
+  /// ```
+  ///
+  /// ## Panics
+  /// * If the bytes aren't utf-8 (see [`as_str`](ZStr::as_str)).
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn trim(self) -> &'a str {
+    self.as_str().trim()
+  }
+
+  /// The string with leading whitespace removed.
+  ///
+  /// ## Panics
+  /// * If the bytes aren't utf-8 (see [`as_str`](ZStr::as_str)).
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn trim_start(self) -> &'a str {
+    self.as_str().trim_start()
+  }
+
+  /// The string with trailing whitespace removed.
+  ///
+  /// ## Panics
+  /// * If the bytes aren't utf-8 (see [`as_str`](ZStr::as_str)).
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn trim_end(self) -> &'a str {
+    self.as_str().trim_end()
+  }
+
+  /// Makes a new [`ZString`] by repeating this string `n` times.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const AB: ZStr<'static> = ZStr::from_lit("ab\0");
+  /// assert_eq!(AB.repeat(3), "ababab");
+  /// assert_eq!(AB.repeat(0), "");
+  /// ```
+  ///
+  /// ## Panics
+  /// * If the total length would overflow `usize`.
+  /// * If the bytes aren't utf-8 (see [`as_str`](ZStr::as_str)).
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  #[cfg(feature = "alloc")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+  pub fn repeat(self, n: usize) -> ZString {
+    let s = self.as_str();
+    let capacity = s
+      .len()
+      .checked_mul(n)
+      .and_then(|len| len.checked_add(1))
+      .expect("capacity overflow");
+    let mut out = alloc::string::String::with_capacity(capacity);
+    for _ in 0..n {
+      out.push_str(s);
+    }
+    out.push('\0');
+    // Safety: The content of a `ZStr` has no nulls, and we've added a single
+    // null to the end.
+    unsafe { ZString::new_unchecked(out.into_boxed_str()) }
+  }
+
+  /// Checks if the bytes of this `ZStr` equal the bytes of a `&str`.
+  ///
+  /// This is the same as the `PartialEq<&str>` impl, but it's a `const fn`.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// const IS_FOO: bool = FOO.eq_str("foo");
+  /// const IS_BAR: bool = FOO.eq_str("bar");
+  /// assert!(IS_FOO);
+  /// assert!(!IS_BAR);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub const fn eq_str(self, other: &str) -> bool {
+    let other = other.as_bytes();
+    let p = self.nn.as_ptr() as *const u8;
+    let mut i = 0;
+    loop {
+      // Safety: We only advance `i` while the byte at `i` is non-zero, so we
+      // never read past the terminator.
+      let b = unsafe { *p.add(i) };
+      if i == other.len() {
+        return b == 0;
+      } else if b == 0 || b != other[i] {
+        return false;
+      }
+      i += 1;
+    }
+  }
+
+  /// Checks if this `ZStr` equals a `&str`, ignoring any trailing `pad` chars
+  /// on the `ZStr`.
+  ///
+  /// This is for fixed width fields that get padded out, usually with spaces
+  /// (so pass `' '` for the common case). Only trailing `pad` chars are
+  /// ignored, any `pad` chars within the string must still match.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FIELD: ZStr<'static> = ZStr::from_lit("a b   \0");
+  /// assert!(FIELD.eq_trimming("a b", ' '));
+  /// assert!(!FIELD.eq_trimming("ab", ' '));
+  /// assert!(!FIELD.eq_trimming("a b", '_'));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn eq_trimming(self, other: &str, pad: char) -> bool {
+    let mut buf = [0_u8; 4];
+    let pad = pad.encode_utf8(&mut buf).as_bytes();
+    let mut bytes = self.as_bytes();
+    while let Some(rest) = bytes.strip_suffix(pad) {
+      bytes = rest;
+    }
+    bytes == other.as_bytes()
+  }
+
+  /// Compares the bytes of two `ZStr` values.
+  ///
+  /// This is the same ordering as the `PartialOrd` impl, but it's a `const fn`
+  /// and it can be passed directly to things like
+  /// [`binary_search_by`](slice::binary_search_by).
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const TABLE: [ZStr<'static>; 3] = [
+  ///   ZStr::from_lit("abc\0"),
+  ///   ZStr::from_lit("def\0"),
+  ///   ZStr::from_lit("ghi\0"),
+  /// ];
+  /// let def = ZStr::try_from("def\0").unwrap();
+  /// assert_eq!(TABLE.binary_search_by(|z| z.cmp_bytes(def)), Ok(1));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub const fn cmp_bytes(self, other: ZStr<'_>) -> Ordering {
+    let a = self.nn.as_ptr() as *const u8;
+    let b = other.nn.as_ptr() as *const u8;
+    let mut i = 0;
+    loop {
+      // Safety: We only advance `i` while both bytes at `i` are non-zero, so
+      // we never read past either terminator.
+      let (x, y) = unsafe { (*a.add(i), *b.add(i)) };
+      if x < y {
+        return Ordering::Less;
+      } else if x > y {
+        return Ordering::Greater;
+      } else if x == 0 {
+        return Ordering::Equal;
+      }
+      i += 1;
+    }
+  }
+
+  /// Converts the data to a `Cow<str>`, replacing any invalid utf-8.
+  ///
+  /// * If the bytes are valid utf-8 you get a `Cow::Borrowed` of the data
+  ///   without any allocation.
+  /// * Otherwise you get a `Cow::Owned` string where each invalid sequence
+  ///   is a [`REPLACEMENT_CHARACTER`](char::REPLACEMENT_CHARACTER), the same
+  ///   as [`String::from_utf8_lossy`](alloc::string::String::from_utf8_lossy).
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// # use std::borrow::Cow;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// assert!(matches!(FOO.to_string_lossy(), Cow::Borrowed("foo")));
+  /// ```
+  #[inline]
+  #[must_use]
+  #[cfg(feature = "alloc")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+  pub fn to_string_lossy(self) -> alloc::borrow::Cow<'a, str> {
+    use alloc::borrow::Cow;
+    match core::str::from_utf8(self.as_bytes()) {
+      Ok(s) => Cow::Borrowed(s),
+      Err(_) => {
+        let bytes = self.as_bytes();
+        let mut string = alloc::string::String::with_capacity(bytes.len());
+        for chunk in bytes.utf8_chunks() {
+          string.push_str(chunk.valid());
+          if !chunk.invalid().is_empty() {
+            string.push(char::REPLACEMENT_CHARACTER);
+          }
+        }
+        Cow::Owned(string)
+      }
+    }
+  }
+}
+impl<'a> IntoIterator for ZStr<'a> {
+  type Item = char;
+  type IntoIter = ZStrChars<'a>;
+  /// Iterates the decoded `char` values, same as [`chars`](ZStr::chars).
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// let mut s = String::new();
+  /// for ch in FOO {
+  ///   s.push(ch);
+  /// }
+  /// for ch in &FOO {
+  ///   s.push(ch);
+  /// }
+  /// assert_eq!(s, "foofoo");
+  /// ```
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.chars()
+  }
+}
+impl<'a> IntoIterator for &ZStr<'a> {
+  type Item = char;
+  type IntoIter = ZStrChars<'a>;
+  /// Iterates the decoded `char` values, same as [`chars`](ZStr::chars).
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    (*self).into_iter()
+  }
+}
+
+/// An iterator over the decoded `char` values of a [`ZStr`].
+///
+/// This is what `chars` returns for [`ZStr`], `ZString`, `ZStringLen`, and
+/// [`ArrayZString`](crate::ArrayZString), so code that walks the `char` values
+/// can name a single type for all of them.
+///
+/// ```rust
+/// # use zstring::*;
+/// fn count_upper(chars: ZStrChars<'_>) -> usize {
+///   chars.filter(|ch| ch.is_uppercase()).count()
+/// }
+/// const FOO: ZStr<'static> = ZStr::from_lit("FooBar\0");
+/// let arr_str: ArrayZString<8> = ArrayZString::try_from("ABC").unwrap();
+/// assert_eq!(count_upper(FOO.chars()), 2);
+/// assert_eq!(count_upper(arr_str.chars()), 3);
+/// ```
+pub type ZStrChars<'a> = CharDecoder<ZStrBytes<'a>>;
+
+/// An iterator over the bytes of a [`ZStr`].
+///
+/// This is what `bytes` returns for [`ZStr`], `ZString`, and
+/// [`ArrayZString`](crate::ArrayZString), so it can be stored in a struct.
+///
+/// * This iterator **excludes** the terminating 0 byte.
+/// * Once it reaches the terminator it keeps returning `None`.
+///
+/// ```rust
+/// # use zstring::*;
+/// struct Parser<'a> {
+///   bytes: ZStrBytes<'a>,
+/// }
+/// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+/// let mut parser = Parser { bytes: FOO.bytes() };
+/// assert_eq!(parser.bytes.next(), Some(b'f'));
+/// ```
+pub struct ZStrBytes<'a> {
+  p: *const u8,
+  limit: usize,
+  life: PhantomData<&'a [u8]>,
+}
+impl ZStrBytes<'_> {
+  /// Makes an iterator that reads bytes until a 0 byte, or until `limit`
+  /// bytes have been read, whichever comes first.
+  ///
+  /// ## Safety
+  /// * The pointer must be valid to read until the first 0 byte, or for
+  ///   `limit` bytes, whichever comes first.
+  #[inline]
+  #[must_use]
+  pub(crate) unsafe fn new(p: *const u8, limit: usize) -> Self {
+    Self { p, limit, life: PhantomData }
+  }
+}
+impl Iterator for ZStrBytes<'_> {
+  type Item = u8;
+
+  #[inline]
+  fn next(&mut self) -> Option<u8> {
+    if self.limit == 0 {
+      return None;
+    }
+    // Safety: `limit` is non-zero and we haven't seen a 0 byte yet, so the
+    // caller of `new` promised this byte is readable.
+    let b = unsafe { self.p.read() };
+    if b == 0 {
+      self.limit = 0;
+      None
+    } else {
+      // Safety: the pointer stays within (or one past) the readable bytes.
+      self.p = unsafe { self.p.add(1) };
+      self.limit -= 1;
+      Some(b)
+    }
+  }
 }
+impl core::iter::FusedIterator for ZStrBytes<'_> {}
+impl core::fmt::Debug for ZStrBytes<'_> {
+  #[inline]
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("ZStrBytes").finish_non_exhaustive()
+  }
+}
+
 impl<'a> TryFrom<&'a str> for ZStr<'a> {
   type Error = ZStringError;
   /// Converts the value in place.
@@ -122,6 +1109,27 @@ impl<'a> TryFrom<&'a str> for ZStr<'a> {
     }
   }
 }
+impl Default for ZStr<'static> {
+  /// The empty string.
+  ///
+  /// This points at a static null byte, it is **not** a null pointer.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// #[derive(Default)]
+  /// struct Layer {
+  ///   name: ZStr<'static>,
+  /// }
+  /// let layer = Layer::default();
+  /// assert_eq!(layer.name, "");
+  /// assert!(!layer.name.as_ptr().is_null());
+  /// ```
+  #[inline]
+  fn default() -> Self {
+    const EMPTY: ZStr<'static> = ZStr::from_lit("\0");
+    EMPTY
+  }
+}
 impl core::fmt::Display for ZStr<'_> {
   /// Display formats the string (without outer `"`).
   ///
@@ -142,20 +1150,64 @@ impl core::fmt::Display for ZStr<'_> {
 impl core::fmt::Debug for ZStr<'_> {
   /// Debug formats with outer `"` around the string.
   ///
+  /// Characters are escaped the same as with the `Debug` of a `str`.
+  ///
   /// ```rust
   /// # use zstring::*;
   /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
   /// let s = format!("{FOO:?}");
   /// assert_eq!(s, "\"foo\"");
+  ///
+  /// const ESCAPES: ZStr<'static> = ZStr::from_lit("a\tb\"c'\0");
+  /// assert_eq!(format!("{ESCAPES:?}"), format!("{:?}", "a\tb\"c'"));
   /// ```
   #[inline]
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     f.write_char('"')?;
-    core::fmt::Display::fmt(self, f)?;
+    for ch in self.chars() {
+      // `str` doesn't escape single quotes, only `char` does.
+      if ch == '\'' {
+        f.write_char(ch)?;
+      } else {
+        write!(f, "{}", ch.escape_debug())?;
+      }
+    }
     f.write_char('"')?;
     Ok(())
   }
 }
+/// The output of [`ZStr::display_truncated`].
+struct DisplayTruncated<'a> {
+  z: ZStr<'a>,
+  max_chars: usize,
+}
+impl core::fmt::Display for DisplayTruncated<'_> {
+  #[inline]
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut chars = self.z.chars();
+    for ch in chars.by_ref().take(self.max_chars) {
+      f.write_char(ch)?;
+    }
+    if chars.next().is_some() {
+      f.write_char('\u{2026}')?;
+    }
+    Ok(())
+  }
+}
+/// The output of [`ZStr::escape_default`].
+struct EscapeDefault<'a> {
+  z: ZStr<'a>,
+}
+impl core::fmt::Display for EscapeDefault<'_> {
+  #[inline]
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    for ch in self.z.chars() {
+      write!(f, "{}", ch.escape_default())?;
+    }
+    Ok(())
+  }
+}
+
 impl core::fmt::Pointer for ZStr<'_> {
   /// Formats the wrapped pointer value.
   #[inline]
@@ -173,6 +1225,10 @@ impl PartialEq<ZStr<'_>> for ZStr<'_> {
   /// process during decoding *could* make two different byte sequences have the
   /// same character sequence).
   ///
+  /// As an optimization, two `ZStr` with the same pointer are equal without
+  /// reading the bytes. This does not change the result: equality is always
+  /// about the bytes, and two different pointers to the same bytes are equal.
+  ///
   /// ```rust
   /// # use zstring::*;
   /// const FOO1: ZStr<'static> = ZStr::from_lit("foo\0");
@@ -249,15 +1305,130 @@ impl PartialOrd<ZString> for ZStr<'_> {
   }
 }
 
+impl AsRef<[u8]> for ZStr<'_> {
+  /// Views the bytes of the string, **excluding** the terminating 0 byte.
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// let bytes: &[u8] = FOO.as_ref();
+  /// assert_eq!(bytes, b"foo");
+  /// ```
+  #[inline]
+  fn as_ref(&self) -> &[u8] {
+    self.as_bytes()
+  }
+}
+impl AsRef<str> for ZStr<'_> {
+  /// Views the string as a `&str` (see [`as_str`](ZStr::as_str)).
+  ///
+  /// ```rust
+  /// # use zstring::*;
+  /// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+  /// let s: &str = FOO.as_ref();
+  /// assert_eq!(s, "foo");
+  /// ```
+  #[inline]
+  #[track_caller]
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
 impl core::hash::Hash for ZStr<'_> {
   #[inline]
   fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-    for b in self.bytes() {
-      state.write_u8(b)
-    }
+    self.hash_bytes(state)
+  }
+}
+
+/// Types that can be viewed as a [`ZStr`].
+///
+/// This is implemented by [`ZStr`], `ZString`, `ZStringLen`, and
+/// [`ArrayZString`](crate::ArrayZString), as well as references to them, so
+/// that FFI wrappers can accept any of them with `impl AsZStr`.
+///
+/// ```rust
+/// # use zstring::*;
+/// fn name_len(name: impl AsZStr) -> usize {
+///   name.as_zstr().bytes().count()
+/// }
+/// const FOO: ZStr<'static> = ZStr::from_lit("foo\0");
+/// let arr_str: ArrayZString<8> = ArrayZString::try_from("hello").unwrap();
+/// assert_eq!(name_len(FOO), 3);
+/// assert_eq!(name_len(&arr_str), 5);
+/// ```
+pub trait AsZStr {
+  /// Borrows the value as a `ZStr`.
+  fn as_zstr(&self) -> ZStr<'_>;
+}
+impl AsZStr for ZStr<'_> {
+  #[inline]
+  fn as_zstr(&self) -> ZStr<'_> {
+    *self
+  }
+}
+impl<T: AsZStr + ?Sized> AsZStr for &T {
+  #[inline]
+  fn as_zstr(&self) -> ZStr<'_> {
+    T::as_zstr(self)
   }
 }
 
+/// Iterates the strings of a "double null terminated" block of bytes.
+///
+/// Windows environment blocks and `REG_MULTI_SZ` registry values use this
+/// layout: a series of null terminated strings, with an extra null (an empty
+/// string) marking the end of the block.
+///
+/// ```rust
+/// # use zstring::*;
+/// let block = b"A=1\0B=2\0\0";
+/// let mut iter = split_nul_terminated_block(block);
+/// assert_eq!(iter.next().unwrap().unwrap(), "A=1");
+/// assert_eq!(iter.next().unwrap().unwrap(), "B=2");
+/// assert!(iter.next().is_none());
+/// ```
+///
+/// * Iteration ends at the first empty string, so any bytes after the final
+///   double null are ignored.
+/// * Iteration also ends at the end of the slice. A missing final null is
+///   allowed, but any bytes after the last null (a string without its own
+///   terminator) are ignored.
+///
+/// ## Failure
+/// * A string that isn't utf-8 gives an `Err` in its place, and then
+///   iteration continues with the next string.
+///
+/// ```rust
+/// # use zstring::*;
+/// let mut iter = split_nul_terminated_block(b"a\0\xFF\0b\0\0");
+/// assert_eq!(iter.next().unwrap().unwrap(), "a");
+/// assert!(iter.next().unwrap().is_err());
+/// assert_eq!(iter.next().unwrap().unwrap(), "b");
+/// assert!(iter.next().is_none());
+/// ```
+#[inline]
+pub fn split_nul_terminated_block(
+  bytes: &[u8],
+) -> impl Iterator<Item = Result<ZStr<'_>, core::str::Utf8Error>> {
+  let mut rest = bytes;
+  core::iter::from_fn(move || {
+    let null_position = rest.iter().position(|&b| b == 0)?;
+    if null_position == 0 {
+      return None;
+    }
+    let (content, new_rest) = rest.split_at(null_position + 1);
+    rest = new_rest;
+    Some(core::str::from_utf8(&content[..null_position]).map(|_| ZStr {
+      // Safety: References can't ever be null, and `content` ends in a null.
+      nn: unsafe { NonNull::new_unchecked(content.as_ptr() as *mut u8) },
+      life: PhantomData,
+    }))
+  })
+  .fuse()
+}
+
 /// An error occurred while trying to make a [`ZStr`] or [`ZString`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ZStringError {
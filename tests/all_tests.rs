@@ -1,4 +1,11 @@
-use zstring::CharDecoder;
+use zstring::{CharDecoder, ZStr};
+
+/// Makes a `ZStr` that isn't necessarily utf-8.
+fn zstr_from_bytes(bytes: &'static [u8]) -> ZStr<'static> {
+  assert_eq!(bytes.last(), Some(&0));
+  // Safety: we just checked that there's a null terminator.
+  unsafe { core::mem::transmute::<*const u8, ZStr<'static>>(bytes.as_ptr()) }
+}
 
 #[test]
 fn bstr_example() {
@@ -22,3 +29,493 @@ fn fuzz_found_data() {
 
   // Note: Other byte sequences will still fail!
 }
+
+#[test]
+fn nul_terminated_block_edge_cases() {
+  use zstring::split_nul_terminated_block;
+
+  let strs = |bytes| -> Vec<Result<&str, ()>> {
+    split_nul_terminated_block(bytes)
+      .map(|r| r.map(|z| z.as_str()).map_err(drop))
+      .collect()
+  };
+  // an empty block is just the final null.
+  assert!(strs(b"\0").is_empty());
+  // no bytes at all, or no terminator, also gives nothing more.
+  assert!(strs(b"").is_empty());
+  assert!(strs(b"abc").is_empty());
+  assert_eq!(strs(b"a\0bc"), [Ok("a")]);
+  // a missing final null still yields the complete strings.
+  assert_eq!(strs(b"a\0b\0"), [Ok("a"), Ok("b")]);
+  // data after the double null is not part of the block.
+  assert_eq!(strs(b"a\0\0b\0\0"), [Ok("a")]);
+  // invalid utf-8 is an error, and doesn't end the block early.
+  assert_eq!(strs(b"a\0\xFF\0b\0\0"), [Ok("a"), Err(()), Ok("b")]);
+  assert_eq!(strs(b"\xC3\0\0"), [Err(())]);
+}
+
+#[test]
+fn array_zstring_hash_ignores_capacity() {
+  use core::hash::{BuildHasher, Hasher};
+  use std::collections::hash_map::RandomState;
+  use zstring::ArrayZString;
+
+  let state = RandomState::new();
+  let small = ArrayZString::<4>::try_from("ab").unwrap();
+  let big = ArrayZString::<8>::try_from("ab").unwrap();
+  assert_eq!(state.hash_one(small), state.hash_one("ab"));
+  assert_eq!(state.hash_one(big), state.hash_one("ab"));
+
+  // bytes that aren't utf-8 still hash (without a panic) like `str` would.
+  let bad: ArrayZString<4> = unsafe { core::mem::transmute(*b"a\xFF\0\0") };
+  let mut expected = state.build_hasher();
+  expected.write(b"a\xFF");
+  expected.write_u8(0xFF);
+  assert_eq!(state.hash_one(bad), expected.finish());
+}
+
+#[test]
+fn array_zstring_eq_ignores_padding() {
+  use zstring::ArrayZString;
+
+  // Safe code always zeroes the padding, but check that garbage after the
+  // first null still isn't compared.
+  let dirty: ArrayZString<6> = unsafe { core::mem::transmute(*b"ab\0xyz") };
+  let clean = ArrayZString::<6>::try_from("ab").unwrap();
+  assert_eq!(dirty, clean);
+  assert_eq!(dirty, ArrayZString::<3>::try_from("ab").unwrap());
+  assert_eq!(dirty, "ab");
+  assert_eq!(dirty.cmp(&clean), core::cmp::Ordering::Equal);
+}
+
+#[test]
+fn zstr_rev_chars_malformed() {
+  let z = zstr_from_bytes;
+  // a lone continuation byte
+  assert!(z(b"a\x80b\0").rev_chars().eq(['b', '\u{FFFD}', 'a']));
+  // a truncated 3 byte sequence
+  assert!(z(b"a\xE2\x82\0").rev_chars().eq(['\u{FFFD}', '\u{FFFD}', 'a']));
+  // too many continuation bytes after a valid sequence
+  assert!(z(b"\xC3\xA9\x80\0").rev_chars().eq(['\u{FFFD}', 'é']));
+  // only continuation bytes
+  assert!(z(b"\x80\x80\x80\x80\x80\0").rev_chars().all(|c| c == '\u{FFFD}'));
+  assert_eq!(z(b"\x80\x80\x80\x80\x80\0").rev_chars().count(), 5);
+}
+
+#[test]
+fn debug_escapes_like_str() {
+  use zstring::ArrayZString;
+
+  for s in ["tab\there", "quote\"d", "new\nline", "bell\x07", "it's", "é😀"]
+  {
+    let arr = ArrayZString::<32>::try_from(s).unwrap();
+    assert_eq!(format!("{arr:?}"), format!("{s:?}"));
+    assert_eq!(format!("{:?}", arr.as_zstr()), format!("{s:?}"));
+  }
+
+  // malformed bytes become replacement characters.
+  let bad = zstr_from_bytes(b"a\xFFb\0");
+  assert_eq!(format!("{bad:?}"), "\"a\u{FFFD}b\"");
+}
+
+#[test]
+fn zstr_eq_pointer_fast_path() {
+  let a = String::from("same\0");
+  let b = String::from("same\0");
+  let za = ZStr::try_from(a.as_str()).unwrap();
+  let zb = ZStr::try_from(b.as_str()).unwrap();
+  // different pointers, equal content
+  assert_ne!(za.as_ptr(), zb.as_ptr());
+  assert_eq!(za, zb);
+  assert_eq!(za.partial_cmp(&zb), Some(core::cmp::Ordering::Equal));
+  // same pointer
+  let za_copy = za;
+  assert_eq!(za.as_ptr(), za_copy.as_ptr());
+  assert_eq!(za, za_copy);
+  // different pointers, different content
+  let c = String::from("diff\0");
+  assert_ne!(za, ZStr::try_from(c.as_str()).unwrap());
+}
+
+#[test]
+fn array_zstring_tiny_capacity() {
+  use zstring::ArrayZString;
+
+  // N == 0 can't even hold the null, so it's always a capacity error.
+  assert_eq!(ArrayZString::<0>::try_from("").unwrap_err(), None);
+  assert_eq!(ArrayZString::<0>::try_from("\0").unwrap_err(), None);
+  assert_eq!(ArrayZString::<0>::try_from("a").unwrap_err(), None);
+
+  // N == 1 can only hold the empty string.
+  let empty = ArrayZString::<1>::try_from("").unwrap();
+  assert_eq!(empty.as_str(), "");
+  assert_eq!(empty.as_zstr(), "");
+  assert!(ArrayZString::<1>::try_from("\0\0").is_ok());
+  assert_eq!(ArrayZString::<1>::try_from("a").unwrap_err(), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn zstr_zstring_cross_comparisons() {
+  use core::cmp::Ordering;
+  use zstring::ZString;
+
+  let zstring = ZString::try_from("abc").unwrap();
+  let same = ZStr::from_lit("abc\0");
+  let less = ZStr::from_lit("abb\0");
+  assert!(same == zstring);
+  assert!(zstring == same);
+  assert!(less != zstring);
+  assert!(zstring != less);
+  assert_eq!(less.partial_cmp(&zstring), Some(Ordering::Less));
+  assert_eq!(zstring.partial_cmp(&less), Some(Ordering::Greater));
+}
+
+#[test]
+fn array_zstring_insert_str() {
+  use zstring::{ArrayZString, ZStringError};
+
+  let mut a = ArrayZString::<8>::try_from("aé").unwrap();
+  a.insert_str(0, "<").unwrap();
+  a.insert_str(2, "-").unwrap();
+  a.insert_str(5, ">").unwrap();
+  assert_eq!(a.as_str(), "<a-é>");
+
+  // exactly fills the capacity
+  a.insert_str(5, "!").unwrap();
+  assert_eq!(a.as_str(), "<a-é!>");
+  // one byte too many
+  assert_eq!(a.insert_str(0, "x"), Err(None));
+  assert_eq!(a.as_str(), "<a-é!>");
+
+  let mut b = ArrayZString::<8>::try_from("ab").unwrap();
+  assert_eq!(b.insert_str(1, "\0"), Err(Some(ZStringError::InteriorNulls)));
+  assert_eq!(b.as_str(), "ab");
+}
+
+#[test]
+#[should_panic]
+fn array_zstring_insert_str_not_char_boundary() {
+  let mut a = zstring::ArrayZString::<8>::try_from("é").unwrap();
+  let _ = a.insert_str(1, "x");
+}
+
+#[test]
+#[should_panic]
+fn array_zstring_insert_str_out_of_bounds() {
+  let mut a = zstring::ArrayZString::<8>::try_from("ab").unwrap();
+  let _ = a.insert_str(3, "x");
+}
+
+#[test]
+fn array_zstring_from_zstr_capacity_boundary() {
+  use zstring::ArrayZString;
+
+  let abc = ZStr::from_lit("abc\0");
+  // exactly `N-1` bytes fits, with the null in the last slot.
+  let exact = ArrayZString::<4>::try_from(abc).unwrap();
+  assert_eq!(exact.as_str(), "abc");
+  assert_eq!(exact.as_array(), b"abc\0");
+  // one byte less of room is a capacity error.
+  assert_eq!(ArrayZString::<3>::try_from(abc).unwrap_err(), None);
+  // a multi-byte char can't be split at the boundary.
+  let e_acute = ZStr::from_lit("aé\0");
+  assert_eq!(ArrayZString::<3>::try_from(e_acute).unwrap_err(), None);
+  assert!(ArrayZString::<4>::try_from(e_acute).is_ok());
+  // the empty string still needs room for the null.
+  let empty = ZStr::from_lit("\0");
+  assert_eq!(ArrayZString::<0>::try_from(empty).unwrap_err(), None);
+  assert_eq!(ArrayZString::<1>::try_from(empty).unwrap().as_str(), "");
+}
+
+#[test]
+fn as_zstr_generic() {
+  use zstring::{ArrayZString, AsZStr};
+
+  fn first_char(s: impl AsZStr) -> Option<char> {
+    s.as_zstr().chars().next()
+  }
+
+  let zstr = ZStr::from_lit("abc\0");
+  assert_eq!(first_char(zstr), Some('a'));
+  let zstr_ref: &ZStr = &zstr;
+  assert_eq!(first_char(zstr_ref), Some('a'));
+
+  let arr_str: ArrayZString<8> = ArrayZString::try_from("def").unwrap();
+  assert_eq!(first_char(arr_str), Some('d'));
+
+  #[cfg(feature = "alloc")]
+  {
+    let zstring = zstring::ZString::try_from("ghi").unwrap();
+    assert_eq!(first_char(&zstring), Some('g'));
+    let zstring_len = zstring::ZStringLen::from(zstring.clone());
+    assert_eq!(first_char(&zstring_len), Some('g'));
+    assert_eq!(first_char(zstring_len), Some('g'));
+    assert_eq!(first_char(zstring), Some('g'));
+  }
+}
+
+#[test]
+fn zstr_concat_pieces() {
+  const EMPTY: ZStr<'static> = zstring::zstr_concat!("");
+  assert_eq!(EMPTY, "");
+  const JOINED: ZStr<'static> = zstring::zstr_concat!("a", "", "bc",);
+  assert_eq!(JOINED, "abc");
+  // the pieces don't have to be string literals, any literal works.
+  const MIXED: ZStr<'static> = zstring::zstr_concat!("v", 1, '.', 2);
+  assert_eq!(MIXED, "v1.2");
+}
+
+#[test]
+fn array_zstring_cross_capacity_ordering() {
+  use core::cmp::Ordering;
+  use zstring::ArrayZString;
+
+  // garbage after the null would sort "ab" after "abc" if it were compared.
+  let dirty: ArrayZString<8> = unsafe { core::mem::transmute(*b"ab\0zzzzz") };
+  let abc = ArrayZString::<4>::try_from("abc").unwrap();
+  assert_eq!(dirty.partial_cmp(&abc), Some(Ordering::Less));
+  assert_eq!(abc.partial_cmp(&dirty), Some(Ordering::Greater));
+  assert!(dirty != abc);
+  assert!(dirty == ArrayZString::<3>::try_from("ab").unwrap());
+}
+
+#[test]
+fn array_zstring_from_array_checked() {
+  use zstring::ArrayZString;
+
+  const EMPTY: ArrayZString<1> = ArrayZString::from_array_checked([0]);
+  assert_eq!(EMPTY.as_str(), "");
+  const FULL: ArrayZString<5> =
+    ArrayZString::from_array_checked(*b"ab\xC3\xA9\0");
+  assert_eq!(FULL.as_str(), "abé");
+}
+
+#[test]
+#[should_panic(expected = "No trailing nulls.")]
+fn array_zstring_from_array_checked_no_null() {
+  let _ = zstring::ArrayZString::from_array_checked(*b"abc");
+}
+
+#[test]
+#[should_panic(expected = "Input is not utf-8.")]
+fn array_zstring_from_array_checked_not_utf8() {
+  let _ = zstring::ArrayZString::from_array_checked(*b"a\xFF\0");
+}
+
+#[test]
+fn char_decoder_size_hint_bounds() {
+  let inputs: [&[u8]; 6] =
+    [b"", b"abc", "aé😀".as_bytes(), b"\xFF\xFF", b"a\xE2\x82z", b"\xF0\x9F"];
+  for bytes in inputs {
+    let total = CharDecoder::from_bytes(bytes).count();
+    for skip in 0..=total {
+      for peek in [false, true] {
+        let mut decoder = CharDecoder::from_bytes(bytes);
+        decoder.by_ref().take(skip).for_each(drop);
+        if peek {
+          decoder.peek();
+        }
+        let (lower, upper) = decoder.size_hint();
+        let remaining = decoder.count();
+        assert!(lower <= remaining, "{bytes:?}: {lower} > {remaining}");
+        assert!(upper.is_some_and(|u| remaining <= u), "{bytes:?}: {upper:?}");
+      }
+    }
+  }
+  // ascii gets an exact upper bound
+  assert_eq!(CharDecoder::from_str("abcd").size_hint(), (1, Some(4)));
+}
+
+#[test]
+fn char_decoder_skip_bom() {
+  let bom_only = CharDecoder::from_bytes(b"\xEF\xBB\xBF").skip_bom();
+  assert_eq!(bom_only.count(), 0);
+  // only the first char is checked, a second BOM is kept.
+  let two_boms = CharDecoder::from_str("\u{FEFF}\u{FEFF}a").skip_bom();
+  assert!(two_boms.eq(['\u{FEFF}', 'a']));
+  // a truncated BOM is just malformed input.
+  let partial = CharDecoder::from_bytes(b"\xEF\xBBa").skip_bom();
+  assert!(partial.eq(['\u{FFFD}', 'a']));
+  assert_eq!(CharDecoder::from_bytes(b"").skip_bom().count(), 0);
+}
+
+#[test]
+fn array_zstring_from_zstr_not_utf8() {
+  use zstring::ArrayZString;
+
+  // an overlong encoding of a null must not become a null in the array.
+  let overlong = zstr_from_bytes(b"a\xE0\x80\x80b\0");
+  let arr_str = ArrayZString::<16>::try_from(overlong).unwrap();
+  assert_eq!(arr_str.as_str(), String::from_utf8_lossy(b"a\xE0\x80\x80b"));
+  let len = arr_str.as_str().len();
+  assert!(arr_str.as_array()[len..].iter().all(|&b| b == 0));
+  let bad = zstr_from_bytes(b"\xFFz\0");
+  assert_eq!(ArrayZString::<5>::try_from(bad).unwrap().as_str(), "\u{FFFD}z");
+  assert_eq!(ArrayZString::<4>::try_from(bad).unwrap_err(), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn zstring_from_utf8_lossy_overlong_null() {
+  use zstring::ZString;
+
+  // overlong encodings of a null must not become an interior null.
+  for bytes in [&b"a\xE0\x80\x80bcdef"[..], b"a\xF0\x80\x80\x80bc", b"\xC0\x80"]
+  {
+    let zstring = ZString::from_utf8_lossy(bytes);
+    let expected = String::from_utf8_lossy(bytes);
+    assert_eq!(zstring, expected.as_ref());
+    assert_eq!(zstring.bytes().count(), expected.len());
+  }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn zstr_to_string_lossy_matches_std() {
+  use std::borrow::Cow;
+
+  let inputs: [&'static [u8]; 6] = [
+    b"plain\0",
+    b"a\xE0\x80\x80b\0",
+    b"\xC0\x80\0",
+    b"a\xF0\x9F\x98\0",
+    b"\xE2\x82z\xFF\0",
+    b"\xED\xA0\x80\0",
+  ];
+  for bytes in inputs {
+    let z = zstr_from_bytes(bytes);
+    let expected = String::from_utf8_lossy(&bytes[..bytes.len() - 1]);
+    let lossy = z.to_string_lossy();
+    assert_eq!(lossy, expected, "{bytes:?}");
+    assert_eq!(
+      matches!(lossy, Cow::Borrowed(_)),
+      matches!(expected, Cow::Borrowed(_))
+    );
+  }
+}
+
+#[test]
+fn zstr_find_byte() {
+  // long enough that `memchr` (with the feature on) takes its vector path.
+  let text = "0123456789abcdef".repeat(12) + "=tail\0";
+  let z = ZStr::try_from(text.as_str()).unwrap();
+  assert_eq!(z.find_byte(b'0'), Some(0));
+  assert_eq!(z.find_byte(b'='), Some(192));
+  assert_eq!(z.find_byte(b'l'), Some(196));
+  assert_eq!(z.find_byte(b'!'), None);
+  assert_eq!(z.find_byte(0), None);
+  for b in 0..=255 {
+    let expected = text.bytes().take_while(|&x| x != 0).position(|x| x == b);
+    assert_eq!(z.find_byte(b), expected, "{b}");
+  }
+  assert_eq!(ZStr::from_lit("\0").find_byte(b'a'), None);
+}
+
+#[test]
+fn array_zstring_compares_with_str() {
+  use core::cmp::Ordering;
+  use zstring::ArrayZString;
+
+  let arr = ArrayZString::<8>::try_from("abb").unwrap();
+  assert!(arr < *"abc");
+  assert!(arr > *"ab");
+  assert!(arr == *"abb");
+  assert!(arr != *"abb\0");
+  assert_eq!(arr.partial_cmp("abb"), Some(Ordering::Equal));
+  // the `&str` forms give the same answers.
+  assert!(arr < "abc");
+  assert!(arr == "abb");
+  assert_eq!(arr.partial_cmp(&"ab"), arr.partial_cmp("ab"));
+}
+
+#[test]
+fn array_zstring_try_concat_errors() {
+  use zstring::{ArrayZString, ZStringError};
+
+  assert_eq!(ArrayZString::<5>::try_concat(&["ab", "cd"]).unwrap(), "abcd");
+  assert_eq!(ArrayZString::<4>::try_concat(&["ab", "cd"]), Err(None));
+  // a null is reported even if the length check alone would fail first.
+  for parts in [&["abcdef", "\0"][..], &["abcdef\0"], &["a", "bcdef", "g\0h"]] {
+    assert_eq!(
+      ArrayZString::<4>::try_concat(parts),
+      Err(Some(ZStringError::InteriorNulls)),
+      "{parts:?}"
+    );
+  }
+}
+
+#[test]
+fn zstr_display_width() {
+  let z = ZStr::from_lit("日本a\0");
+  #[cfg(feature = "unicode-width")]
+  assert_eq!(z.display_width(), 5);
+  #[cfg(not(feature = "unicode-width"))]
+  assert_eq!(z.display_width(), 3);
+  assert_eq!(ZStr::from_lit("\0").display_width(), 0);
+}
+
+#[test]
+fn array_zstring_zero_capacity_compares() {
+  use core::{cmp::Ordering, hash::BuildHasher};
+  use std::collections::hash_map::RandomState;
+  use zstring::ArrayZString;
+
+  let a = ArrayZString::<0>::default();
+  let b = ArrayZString::<0>::default();
+  assert!(a == b);
+  assert_eq!(a.cmp(&b), Ordering::Equal);
+  assert!(a == ArrayZString::<4>::default());
+  assert_eq!(a.bytes().count(), 0);
+  assert_eq!(a.chars().count(), 0);
+  let state = RandomState::new();
+  assert_eq!(state.hash_one(a), state.hash_one(b));
+}
+
+#[test]
+fn array_zstring_bytes_stay_in_bounds() {
+  use zstring::ArrayZString;
+
+  // unsafe code broke the "must have a null" rule, and the array is followed
+  // by more non-null bytes, but reading still stops after all `N` bytes.
+  #[repr(C)]
+  struct Padded {
+    arr: ArrayZString<4>,
+    after: [u8; 4],
+  }
+  let padded = Padded {
+    arr: unsafe {
+      core::mem::transmute::<[u8; 4], ArrayZString<4>>(*b"ab\xC3\xA9")
+    },
+    after: *b"zzzz",
+  };
+  assert_eq!(padded.after, *b"zzzz");
+  assert_eq!(padded.arr.bytes().count(), 4);
+  assert!(padded.arr.bytes().eq(*b"ab\xC3\xA9"));
+  assert!(padded.arr.chars().eq("ab\u{e9}".chars()));
+  assert!(ArrayZString::<0>::const_default().bytes().eq([]));
+}
+
+#[test]
+fn char_decoder_fold_matches_next() {
+  let inputs: [&[u8]; 5] = [
+    b"plain ascii",
+    "h\u{e9}llo \u{65e5}\u{672c} \u{1F600}!".as_bytes(),
+    b"a\xFFb\xC3c\xE0\x80\x80d\xF0\x9F\x98",
+    b"\xED\xA0\x80\xC0\xAFz",
+    b"",
+  ];
+  for bytes in inputs {
+    // `from_fn` only ever calls `next`, never the `fold` override.
+    let mut decoder = CharDecoder::from_bytes(bytes);
+    let stepped: String = core::iter::from_fn(|| decoder.next()).collect();
+    let folded: String = CharDecoder::from_bytes(bytes).collect();
+    assert_eq!(stepped, folded);
+    // a peeked value must come out first when folding.
+    let mut peeked = CharDecoder::from_bytes(bytes);
+    let first = peeked.peek();
+    let rest: String = peeked.collect();
+    assert_eq!(rest.chars().next(), first);
+    assert_eq!(rest, folded);
+  }
+}